@@ -27,6 +27,9 @@ import Discovery from './pages/Discovery'
 import Social from './pages/Social'
 import Finance from './pages/Finance'
 import CloudStorage from './pages/CloudStorage'
+import Onboarding from './pages/Onboarding'
+import Settings from './pages/Settings'
+import Troubleshooting from './pages/Troubleshooting'
 
 function App() {
   return (
@@ -35,6 +38,9 @@ function App() {
         {/* Core */}
         <Route index element={<Dashboard />} />
         <Route path="status" element={<Status />} />
+        <Route path="onboarding" element={<Onboarding />} />
+        <Route path="settings" element={<Settings />} />
+        <Route path="troubleshooting" element={<Troubleshooting />} />
 
         {/* Account Management */}
         <Route path="accounts" element={<Accounts />} />
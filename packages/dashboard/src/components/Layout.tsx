@@ -10,7 +10,7 @@
 // version: 1.0.0
 // ==============================================================================
 
-import { Outlet, NavLink, useLocation } from 'react-router-dom'
+import { Outlet, NavLink, useLocation, useNavigate } from 'react-router-dom'
 import {
   LayoutDashboard,
   Users,
@@ -33,6 +33,8 @@ import {
   ChevronRight
 } from 'lucide-react'
 import { useState, useEffect } from 'react'
+import { getTauri } from '../tauri'
+import SafeModeBanner from './SafeModeBanner'
 
 interface NavSection {
   title: string
@@ -100,6 +102,19 @@ export default function Layout() {
     new Set(navSections.map(s => s.title))
   )
   const location = useLocation()
+  const navigate = useNavigate()
+
+  // The desktop shell routes through the SPA router (e.g. tray "System Status").
+  // dashboard-ready tells it the listener is in place, so launch-time routes aren't lost.
+  useEffect(() => {
+    const tauri = getTauri()
+    if (!tauri) return
+    const unlisten = tauri.event.listen<string>('navigate', (event) => navigate(event.payload))
+    unlisten.then(() => tauri.event.emit('dashboard-ready'))
+    return () => {
+      unlisten.then((stop) => stop())
+    }
+  }, [navigate])
 
   // Check system health periodically
   useEffect(() => {
@@ -267,6 +282,7 @@ export default function Layout() {
 
       {/* Main Content */}
       <main className="flex-1 overflow-auto">
+        <SafeModeBanner />
         <Outlet />
       </main>
     </div>
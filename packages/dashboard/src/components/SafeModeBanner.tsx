@@ -0,0 +1,86 @@
+// ==============================================================================
+// file_id: SOM-SCR-0117-v1.0.0
+// name: SafeModeBanner.tsx
+// description: AEGIS Desktop banner shown while automatic restarts have given up
+// project_id: AEGIS
+// category: component
+// tags: [dashboard, safe-mode, desktop]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+import { useEffect, useState } from 'react'
+import { Link } from 'react-router-dom'
+import { AlertTriangle, Loader2 } from 'lucide-react'
+import { getTauri } from '../tauri'
+
+interface SafeModeStatus {
+  active: boolean
+  reason: string | null
+}
+
+export default function SafeModeBanner() {
+  const [status, setStatus] = useState<SafeModeStatus>({ active: false, reason: null })
+  const [restarting, setRestarting] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  // Safe mode can be entered before the dashboard loads, so read it once and then follow
+  // safe-mode-changed
+  useEffect(() => {
+    const tauri = getTauri()
+    if (!tauri) return
+    tauri.core.invoke<SafeModeStatus>('get_safe_mode').then(setStatus).catch(console.error)
+    const unlisten = tauri.event.listen<SafeModeStatus>('safe-mode-changed', (event) =>
+      setStatus(event.payload)
+    )
+    return () => {
+      unlisten.then((stop) => stop())
+    }
+  }, [])
+
+  if (!status.active) return null
+
+  // restart_api leaves safe mode and turns automatic restarts back on
+  const restartNormally = async () => {
+    const tauri = getTauri()
+    if (!tauri) return
+    setRestarting(true)
+    setError(null)
+    try {
+      const healthy = await tauri.core.invoke<boolean>('restart_api')
+      if (!healthy) setError('The API server restarted but is not healthy yet.')
+    } catch (e) {
+      setError(String(e))
+    } finally {
+      setRestarting(false)
+    }
+  }
+
+  return (
+    <div className="flex items-start gap-3 px-6 py-4 bg-yellow-900/40 border-b border-yellow-700">
+      <AlertTriangle className="w-5 h-5 text-yellow-400 shrink-0 mt-0.5" />
+      <div className="flex-1">
+        <p className="text-yellow-100 font-medium">
+          Safe mode: AEGIS stopped restarting the API server after repeated crashes.
+        </p>
+        {status.reason && <p className="text-sm text-yellow-200/80 mt-1">{status.reason}</p>}
+        {error && <p className="text-sm text-red-400 mt-1">{error}</p>}
+      </div>
+      <Link
+        to="/troubleshooting"
+        className="px-3 py-1.5 text-yellow-100 hover:text-white transition-colors"
+      >
+        Troubleshoot
+      </Link>
+      <button
+        onClick={restartNormally}
+        disabled={restarting}
+        className="flex items-center gap-2 px-3 py-1.5 bg-yellow-600 hover:bg-yellow-500 disabled:opacity-50 rounded-lg text-white transition-colors"
+      >
+        {restarting && <Loader2 className="w-4 h-4 animate-spin" />}
+        Restart normally
+      </button>
+    </div>
+  )
+}
@@ -0,0 +1,100 @@
+// ==============================================================================
+// file_id: SOM-SCR-0086-v1.0.0
+// name: Onboarding.tsx
+// description: AEGIS Desktop first-run walkthrough
+// project_id: AEGIS
+// category: component
+// tags: [dashboard, onboarding, desktop]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+import { useState } from 'react'
+import { useNavigate } from 'react-router-dom'
+import { Shield, PanelTop, Power, Server, ArrowRight } from 'lucide-react'
+import { getTauri } from '../tauri'
+
+const steps = [
+  {
+    icon: PanelTop,
+    title: 'Lives in your tray',
+    body: 'Closing the window keeps AEGIS running in the system tray. Click the shield icon to bring the dashboard back, or press Ctrl+Shift+A (Cmd+Shift+A on macOS).'
+  },
+  {
+    icon: Power,
+    title: 'Start at login',
+    body: 'AEGIS can launch when you log in and go straight to the tray, so protection is on without a window popping up. You can change this any time from the tray menu.'
+  },
+  {
+    icon: Server,
+    title: 'Local API server',
+    body: 'The dashboard talks to a local API server on port 4243. If that port is taken, set "port" under [api] in aegis.toml in the app config folder or the AEGIS_API_PORT environment variable.'
+  }
+]
+
+export default function Onboarding() {
+  const navigate = useNavigate()
+  const [autostart, setAutostart] = useState(false)
+  const tauri = getTauri()
+
+  const finish = async (enableAutostart: boolean) => {
+    if (tauri) {
+      if (enableAutostart) {
+        await tauri.core.invoke('enable_autostart').catch(console.error)
+      }
+      await tauri.core.invoke('complete_onboarding').catch(console.error)
+    }
+    navigate('/')
+  }
+
+  return (
+    <div className="p-8 max-w-3xl mx-auto">
+      <div className="flex items-center gap-3 mb-2">
+        <Shield className="w-8 h-8 text-aegis-400" />
+        <h1 className="text-3xl font-bold text-white">Welcome to AEGIS</h1>
+      </div>
+      <p className="text-gray-400 mb-8">A quick look at how the desktop app works.</p>
+
+      <div className="space-y-4 mb-8">
+        {steps.map(({ icon: Icon, title, body }) => (
+          <div key={title} className="flex gap-4 p-5 rounded-xl bg-gray-800/50 border border-gray-700">
+            <Icon className="w-6 h-6 text-aegis-400 shrink-0 mt-1" />
+            <div>
+              <h2 className="text-lg font-semibold text-white">{title}</h2>
+              <p className="text-gray-400 mt-1">{body}</p>
+            </div>
+          </div>
+        ))}
+      </div>
+
+      {tauri && (
+        <label className="flex items-center gap-3 mb-8 text-gray-300">
+          <input
+            type="checkbox"
+            checked={autostart}
+            onChange={(e) => setAutostart(e.target.checked)}
+            className="w-4 h-4"
+          />
+          Launch AEGIS when I log in
+        </label>
+      )}
+
+      <div className="flex items-center justify-between">
+        <button
+          onClick={() => finish(false)}
+          className="px-3 py-1.5 text-gray-400 hover:text-gray-200 transition-colors"
+        >
+          Skip
+        </button>
+        <button
+          onClick={() => finish(autostart)}
+          className="flex items-center gap-2 px-4 py-2 bg-aegis-600 hover:bg-aegis-500 rounded-lg text-white transition-colors"
+        >
+          Get started
+          <ArrowRight className="w-4 h-4" />
+        </button>
+      </div>
+    </div>
+  )
+}
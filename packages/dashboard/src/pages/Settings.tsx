@@ -0,0 +1,88 @@
+// ==============================================================================
+// file_id: SOM-SCR-0090-v1.0.0
+// name: Settings.tsx
+// description: AEGIS Desktop settings window
+// project_id: AEGIS
+// category: component
+// tags: [dashboard, settings, desktop]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+import { useState } from 'react'
+import { Settings as SettingsIcon, LifeBuoy, Loader2 } from 'lucide-react'
+import { getTauri } from '../tauri'
+
+interface HealthReport {
+  reachable: boolean
+  status_code: number | null
+  latency_ms: number | null
+  error: string | null
+  wrong_service: boolean
+}
+
+export default function Settings() {
+  const tauri = getTauri()
+  const [recovering, setRecovering] = useState(false)
+  const [result, setResult] = useState<{ ok: boolean; message: string } | null>(null)
+
+  const recover = async () => {
+    if (!tauri) return
+    setRecovering(true)
+    setResult(null)
+    try {
+      const report = await tauri.core.invoke<HealthReport>('recover')
+      const healthy = !report.wrong_service && report.status_code !== null && report.status_code < 300
+      setResult({
+        ok: healthy,
+        message: healthy
+          ? `Backend is back up (${report.latency_ms}ms).`
+          : `Backend restarted but is not healthy yet: ${report.error ?? 'no response'}`
+      })
+    } catch (e) {
+      setResult({ ok: false, message: String(e) })
+    } finally {
+      setRecovering(false)
+    }
+  }
+
+  return (
+    <div className="p-8 max-w-3xl mx-auto">
+      <div className="flex items-center gap-3 mb-8">
+        <SettingsIcon className="w-8 h-8 text-aegis-400" />
+        <h1 className="text-3xl font-bold text-white">Settings</h1>
+      </div>
+
+      {!tauri && (
+        <p className="text-gray-400">These settings are only available in the AEGIS desktop app.</p>
+      )}
+
+      {tauri && (
+        <div className="p-5 rounded-xl bg-gray-800/50 border border-gray-700">
+          <div className="flex gap-4">
+            <LifeBuoy className="w-6 h-6 text-aegis-400 shrink-0 mt-1" />
+            <div className="flex-1">
+              <h2 className="text-lg font-semibold text-white">Recovery</h2>
+              <p className="text-gray-400 mt-1">
+                Stops the API server, clears its buffered logs, waits for the port to be released
+                and starts it again. Use this when the dashboard stops responding.
+              </p>
+              <button
+                onClick={recover}
+                disabled={recovering}
+                className="flex items-center gap-2 mt-4 px-4 py-2 bg-aegis-600 hover:bg-aegis-500 disabled:opacity-50 rounded-lg text-white transition-colors"
+              >
+                {recovering && <Loader2 className="w-4 h-4 animate-spin" />}
+                {recovering ? 'Recovering…' : 'Recover'}
+              </button>
+              {result && (
+                <p className={`mt-3 ${result.ok ? 'text-green-400' : 'text-red-400'}`}>{result.message}</p>
+              )}
+            </div>
+          </div>
+        </div>
+      )}
+    </div>
+  )
+}
@@ -0,0 +1,169 @@
+// ==============================================================================
+// file_id: SOM-SCR-0116-v1.0.0
+// name: Troubleshooting.tsx
+// description: AEGIS Desktop troubleshooting page for a backend that won't start
+// project_id: AEGIS
+// category: component
+// tags: [dashboard, troubleshooting, desktop]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+import { useCallback, useEffect, useState } from 'react'
+import { Wrench, CheckCircle, XCircle, Loader2, RefreshCw, LifeBuoy } from 'lucide-react'
+import { getTauri } from '../tauri'
+
+// Rows of environment_report and run_connectivity_test
+interface Check {
+  name: string
+  passed: boolean
+  critical?: boolean
+  detail: string
+}
+
+interface HealthReport {
+  status_code: number | null
+  latency_ms: number | null
+  error: string | null
+  wrong_service: boolean
+}
+
+function CheckList({ checks }: { checks: Check[] }) {
+  return (
+    <ul className="space-y-2 mt-4">
+      {checks.map((check) => (
+        <li key={check.name} className="flex gap-3">
+          {check.passed ? (
+            <CheckCircle className="w-5 h-5 text-green-400 shrink-0 mt-0.5" />
+          ) : (
+            <XCircle
+              className={`w-5 h-5 shrink-0 mt-0.5 ${check.critical === false ? 'text-yellow-400' : 'text-red-400'}`}
+            />
+          )}
+          <div>
+            <span className="text-white">{check.name}</span>
+            <p className="text-sm text-gray-400 break-all">{check.detail}</p>
+          </div>
+        </li>
+      ))}
+    </ul>
+  )
+}
+
+export default function Troubleshooting() {
+  const tauri = getTauri()
+  const [environment, setEnvironment] = useState<Check[] | null>(null)
+  const [connectivity, setConnectivity] = useState<Check[] | null>(null)
+  const [running, setRunning] = useState(false)
+  const [recovering, setRecovering] = useState(false)
+  const [result, setResult] = useState<{ ok: boolean; message: string } | null>(null)
+
+  const runChecks = useCallback(async () => {
+    if (!tauri) return
+    setRunning(true)
+    try {
+      const [env, net] = await Promise.all([
+        tauri.core.invoke<Check[]>('environment_report'),
+        tauri.core.invoke<Check[]>('run_connectivity_test', { includeInternet: false })
+      ])
+      setEnvironment(env)
+      setConnectivity(net)
+    } catch (e) {
+      setResult({ ok: false, message: String(e) })
+    } finally {
+      setRunning(false)
+    }
+  }, [tauri])
+
+  useEffect(() => {
+    runChecks()
+  }, [runChecks])
+
+  const recover = async () => {
+    if (!tauri) return
+    setRecovering(true)
+    setResult(null)
+    try {
+      const report = await tauri.core.invoke<HealthReport>('recover')
+      const healthy = !report.wrong_service && report.status_code !== null && report.status_code < 300
+      setResult({
+        ok: healthy,
+        message: healthy
+          ? `Backend is back up (${report.latency_ms}ms).`
+          : `Backend restarted but is not healthy yet: ${report.error ?? 'no response'}`
+      })
+      await runChecks()
+    } catch (e) {
+      setResult({ ok: false, message: String(e) })
+    } finally {
+      setRecovering(false)
+    }
+  }
+
+  return (
+    <div className="p-8 max-w-3xl mx-auto">
+      <div className="flex items-center gap-3 mb-2">
+        <Wrench className="w-8 h-8 text-aegis-400" />
+        <h1 className="text-3xl font-bold text-white">Troubleshooting</h1>
+      </div>
+      <p className="text-gray-400 mb-8">What the API server needs to start, and whether it can be reached.</p>
+
+      {!tauri && (
+        <p className="text-gray-400">These checks are only available in the AEGIS desktop app.</p>
+      )}
+
+      {tauri && (
+        <div className="space-y-4">
+          {[
+            { title: 'Environment', checks: environment },
+            { title: 'Connectivity', checks: connectivity }
+          ].map(({ title, checks }) => (
+            <div key={title} className="p-5 rounded-xl bg-gray-800/50 border border-gray-700">
+              <h2 className="text-lg font-semibold text-white">{title}</h2>
+              {checks ? (
+                <CheckList checks={checks} />
+              ) : (
+                <Loader2 className="w-5 h-5 mt-4 text-gray-400 animate-spin" />
+              )}
+            </div>
+          ))}
+
+          <div className="p-5 rounded-xl bg-gray-800/50 border border-gray-700">
+            <div className="flex gap-4">
+              <LifeBuoy className="w-6 h-6 text-aegis-400 shrink-0 mt-1" />
+              <div className="flex-1">
+                <h2 className="text-lg font-semibold text-white">Recovery</h2>
+                <p className="text-gray-400 mt-1">
+                  Stops the API server, clears its buffered logs, waits for the port to be released
+                  and starts it again.
+                </p>
+                <div className="flex gap-3 mt-4">
+                  <button
+                    onClick={recover}
+                    disabled={recovering}
+                    className="flex items-center gap-2 px-4 py-2 bg-aegis-600 hover:bg-aegis-500 disabled:opacity-50 rounded-lg text-white transition-colors"
+                  >
+                    {recovering && <Loader2 className="w-4 h-4 animate-spin" />}
+                    {recovering ? 'Recovering…' : 'Recover'}
+                  </button>
+                  <button
+                    onClick={runChecks}
+                    disabled={running}
+                    className="flex items-center gap-2 px-4 py-2 bg-gray-700 hover:bg-gray-600 disabled:opacity-50 rounded-lg text-white transition-colors"
+                  >
+                    <RefreshCw className={`w-4 h-4 ${running ? 'animate-spin' : ''}`} />
+                    Run checks again
+                  </button>
+                </div>
+                {result && (
+                  <p className={`mt-3 ${result.ok ? 'text-green-400' : 'text-red-400'}`}>{result.message}</p>
+                )}
+              </div>
+            </div>
+          </div>
+        </div>
+      )}
+    </div>
+  )
+}
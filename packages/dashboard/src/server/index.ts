@@ -23,6 +23,7 @@ import { existsSync } from 'fs'
 // Import privacy suite services
 import { getDMBTService, DMBTStats } from './services/dmbt'
 import { getGhostShellService, GhostShellStats } from './services/ghost-shell'
+import { describeTarget, listen, listenTarget } from './listen'
 
 // Initialize logger
 const logger = createLogger('AEGISServer', {
@@ -32,7 +33,8 @@ const logger = createLogger('AEGISServer', {
 
 const app = express()
 const prisma = new PrismaClient()
-const PORT = 4243
+// The desktop app's api.socket_path arrives as AEGIS_API_SOCKET, otherwise AEGIS_API_PORT
+const LISTEN_TARGET = listenTarget()
 
 // Service instances
 const dmbtService = getDMBTService()
@@ -50,12 +52,25 @@ app.use((req, _res, next) => {
   next()
 })
 
+// In-flight request tracking for /api/busy (the desktop app asks before quitting)
+let activeRequests = 0
+app.use((req, res, next) => {
+  if (req.path === '/api/busy' || req.path === '/api/health') return next()
+  activeRequests++
+  res.once('close', () => {
+    activeRequests--
+  })
+  next()
+})
+
 // ============================================================================
 // HEALTH & STATUS
 // ============================================================================
 
 app.get('/api/health', (_req, res) => {
   res.json({
+    // The desktop app checks this to tell AEGIS apart from whatever else may hold the port
+    service: 'aegis',
     status: 'ok',
     version: '1.0.0',
     timestamp: new Date().toISOString(),
@@ -66,6 +81,15 @@ app.get('/api/health', (_req, res) => {
   })
 })
 
+// Whether work is in progress that quitting would interrupt
+app.get('/api/busy', (_req, res) => {
+  res.json({
+    busy: activeRequests > 0 || runningProcesses.size > 0,
+    activeRequests,
+    runningProcesses: runningProcesses.size
+  })
+})
+
 // Full system status
 app.get('/api/status', async (_req, res) => {
   const dmbtStats = dmbtService.getStats()
@@ -831,13 +855,19 @@ function generatePrivacyRecommendations(dmbt: DMBTStats, ghost: GhostShellStats)
 // START SERVER
 // ============================================================================
 
-app.listen(PORT, () => {
-  logger.info('='.repeat(60))
-  logger.info(`AEGIS Privacy Suite v1.0 API Server`)
-  logger.info(`Running on http://localhost:${PORT}`)
-  logger.info('='.repeat(60))
-  logger.info(`DMBT Database: ${dmbtService.isConnected() ? 'Connected' : 'Not Found'}`)
-  logger.info(`Ghost_Shell Database: ${ghostShellService.isConnected() ? 'Connected' : 'Not Found'}`)
-  logger.info('='.repeat(60))
-  console.log(`🛡️  AEGIS Privacy Suite v1.0 running on http://localhost:${PORT}`)
-})
+listen(app, LISTEN_TARGET)
+  .then(() => {
+    const address = describeTarget(LISTEN_TARGET)
+    logger.info('='.repeat(60))
+    logger.info(`AEGIS Privacy Suite v1.0 API Server`)
+    logger.info(`Running on ${address}`)
+    logger.info('='.repeat(60))
+    logger.info(`DMBT Database: ${dmbtService.isConnected() ? 'Connected' : 'Not Found'}`)
+    logger.info(`Ghost_Shell Database: ${ghostShellService.isConnected() ? 'Connected' : 'Not Found'}`)
+    logger.info('='.repeat(60))
+    console.log(`🛡️  AEGIS Privacy Suite v1.0 running on ${address}`)
+  })
+  .catch((error: Error) => {
+    logger.error(`Could not listen on ${describeTarget(LISTEN_TARGET)}`, error)
+    process.exit(1)
+  })
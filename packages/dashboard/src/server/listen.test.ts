@@ -0,0 +1,86 @@
+// ==============================================================================
+// file_id: SOM-SCR-0115-v1.0.0
+// name: listen.test.ts
+// description: AEGIS API Server - Tests for listening on the Unix socket
+// project_id: AEGIS
+// category: server
+// tags: [api, server, unix-socket, test]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+import { test } from 'node:test'
+import assert from 'node:assert/strict'
+import { get, RequestListener, Server } from 'http'
+import { writeFileSync, existsSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { listen, listenTarget } from './listen'
+
+const handler: RequestListener = (_req, res) => {
+  res.setHeader('Content-Type', 'application/json')
+  res.end(JSON.stringify({ service: 'aegis' }))
+}
+
+function socketPath(name: string): string {
+  return join(tmpdir(), `aegis-${process.pid}-${name}.sock`)
+}
+
+function probe(socket: string): Promise<{ status?: number; body: string }> {
+  return new Promise((resolve, reject) => {
+    get({ socketPath: socket, path: '/api/health' }, (res) => {
+      let body = ''
+      res.setEncoding('utf8')
+      res.on('data', (chunk) => (body += chunk))
+      res.on('end', () => resolve({ status: res.statusCode, body }))
+    }).on('error', reject)
+  })
+}
+
+function close(server: Server): Promise<void> {
+  return new Promise((resolve) => server.close(() => resolve()))
+}
+
+test('AEGIS_API_SOCKET takes precedence over the port', () => {
+  assert.deepEqual(listenTarget({ AEGIS_API_SOCKET: '/tmp/a.sock', AEGIS_API_PORT: '4250' }), {
+    socket: '/tmp/a.sock',
+  })
+  assert.deepEqual(listenTarget({ AEGIS_API_PORT: '4250' }), { port: 4250 })
+  assert.deepEqual(listenTarget({}), { port: 4243 })
+})
+
+test('serves requests over the socket', { skip: process.platform === 'win32' }, async () => {
+  const socket = socketPath('serve')
+  const server = await listen(handler, { socket })
+  try {
+    const response = await probe(socket)
+    assert.equal(response.status, 200)
+    assert.deepEqual(JSON.parse(response.body), { service: 'aegis' })
+  } finally {
+    await close(server)
+  }
+  assert.equal(existsSync(socket), false)
+})
+
+test('replaces a stale socket file', { skip: process.platform === 'win32' }, async () => {
+  const socket = socketPath('stale')
+  writeFileSync(socket, '')
+  const server = await listen(handler, { socket })
+  try {
+    assert.equal((await probe(socket)).status, 200)
+  } finally {
+    await close(server)
+  }
+})
+
+test('refuses a socket another server is using', { skip: process.platform === 'win32' }, async () => {
+  const socket = socketPath('busy')
+  const server = await listen(handler, { socket })
+  try {
+    await assert.rejects(listen(handler, { socket }), /already in use/)
+    assert.equal((await probe(socket)).status, 200)
+  } finally {
+    await close(server)
+  }
+})
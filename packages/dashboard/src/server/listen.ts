@@ -0,0 +1,71 @@
+// ==============================================================================
+// file_id: SOM-SCR-0114-v1.0.0
+// name: listen.ts
+// description: AEGIS API Server - Listen on the TCP port or the desktop app's Unix socket
+// project_id: AEGIS
+// category: server
+// tags: [api, express, server, unix-socket]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+import { createServer, RequestListener, Server } from 'http'
+import { createConnection } from 'net'
+import { existsSync, unlinkSync } from 'fs'
+
+export const DEFAULT_PORT = 4243
+
+export type ListenTarget = { socket: string } | { port: number }
+
+// AEGIS_API_SOCKET (set by the desktop app for api.socket_path) wins over AEGIS_API_PORT
+export function listenTarget(env: NodeJS.ProcessEnv = process.env): ListenTarget {
+  const socket = env.AEGIS_API_SOCKET
+  if (socket) return { socket }
+  return { port: Number(env.AEGIS_API_PORT) || DEFAULT_PORT }
+}
+
+export function describeTarget(target: ListenTarget): string {
+  return 'socket' in target ? `unix:${target.socket}` : `http://localhost:${target.port}`
+}
+
+// True when something accepts connections on the socket
+function socketInUse(path: string): Promise<boolean> {
+  return new Promise((resolve) => {
+    const probe = createConnection(path)
+    probe.once('connect', () => {
+      probe.end()
+      resolve(true)
+    })
+    probe.once('error', () => resolve(false))
+  })
+}
+
+// A socket file left by a killed server makes listen fail with EADDRINUSE; remove it
+// unless a live server still answers there
+export async function removeStaleSocket(path: string): Promise<void> {
+  if (!existsSync(path)) return
+  if (await socketInUse(path)) {
+    throw new Error(`Socket ${path} is already in use by another server`)
+  }
+  unlinkSync(path)
+}
+
+export async function listen(handler: RequestListener, target: ListenTarget): Promise<Server> {
+  if ('socket' in target) await removeStaleSocket(target.socket)
+
+  const server = createServer(handler)
+  await new Promise<void>((resolve, reject) => {
+    server.once('error', reject)
+    const onListening = () => {
+      server.off('error', reject)
+      resolve()
+    }
+    if ('socket' in target) {
+      server.listen(target.socket, onListening)
+    } else {
+      server.listen(target.port, onListening)
+    }
+  })
+  return server
+}
@@ -0,0 +1,27 @@
+// ==============================================================================
+// file_id: SOM-SCR-0085-v1.0.0
+// name: tauri.ts
+// description: AEGIS Dashboard - Access to the Tauri desktop shell when running inside it
+// project_id: AEGIS
+// category: utility
+// tags: [tauri, desktop, bridge]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+// Subset of the global injected by the desktop shell (app.withGlobalTauri)
+export interface TauriGlobal {
+  core: {
+    invoke<T>(command: string, args?: Record<string, unknown>): Promise<T>
+  }
+  event: {
+    listen<T>(event: string, handler: (event: { payload: T }) => void): Promise<() => void>
+    emit(event: string, payload?: unknown): Promise<void>
+  }
+}
+
+// Undefined in a plain browser, where the dashboard runs without the desktop shell
+export function getTauri(): TauriGlobal | undefined {
+  return (window as unknown as { __TAURI__?: TauriGlobal }).__TAURI__
+}
@@ -1,3 +1,20 @@
+use std::process::Command;
+
 fn main() {
+    // Build metadata shown in the About dialog
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".into());
+    println!("cargo:rustc-env=AEGIS_GIT_COMMIT={}", commit);
+    println!(
+        "cargo:rustc-env=AEGIS_BUILD_DATE={}",
+        chrono::Utc::now().format("%Y-%m-%d")
+    );
+    println!("cargo:rerun-if-changed=../../../.git/HEAD");
+
     tauri_build::build()
 }
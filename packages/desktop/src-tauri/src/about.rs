@@ -0,0 +1,94 @@
+// ==============================================================================
+// file_id: SOM-SCR-0082-v1.0.0
+// name: about.rs
+// description: AEGIS Desktop - Version and build information for the About dialog
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, about]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::i18n;
+use crate::runtime::{self, NodeSource};
+use crate::status;
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AboutInfo {
+    pub version: String,
+    pub tauri_version: String,
+    pub build_date: String,
+    pub git_commit: String,
+    // Reported by the running backend; None when it is unreachable
+    pub backend_version: Option<String>,
+    pub api_port: u16,
+    pub api_base_url: String,
+    // None when no Node.js runtime could be found
+    pub node_source: Option<NodeSource>,
+    // NODE_OPTIONS the server is started with, including node_flags
+    pub node_options: Option<String>,
+}
+
+pub async fn collect(app: &AppHandle) -> AboutInfo {
+    let config = app.state::<ConfigStore>().api();
+    let client = app.state::<ApiClient>();
+    let backend_version = status::fetch(&client, &config)
+        .await
+        .ok()
+        .map(|status| status.version);
+
+    AboutInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        tauri_version: tauri::VERSION.to_string(),
+        build_date: env!("AEGIS_BUILD_DATE").to_string(),
+        git_commit: env!("AEGIS_GIT_COMMIT").to_string(),
+        backend_version,
+        api_port: config.port,
+        api_base_url: config.base_url(),
+        node_source: runtime::resolve(config.resource_dir.as_deref())
+            .ok()
+            .map(|node_runtime| node_runtime.source),
+        node_options: config.node_options(),
+    }
+}
+
+// Native dialog with the same details, opened from the tray
+pub fn show_dialog(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let info = collect(&app).await;
+        let node = match info.node_source {
+            Some(NodeSource::Bundled) => "bundled",
+            Some(NodeSource::System) => "system",
+            None => "not found",
+        };
+        let message = format!(
+            "AEGIS Privacy Suite {}\n\n\
+             Build: {} ({})\n\
+             Tauri: {}\n\
+             Backend: {}\n\
+             API: {} (port {})\n\
+             Node.js: {}",
+            info.version,
+            info.git_commit,
+            info.build_date,
+            info.tauri_version,
+            info.backend_version.as_deref().unwrap_or("not running"),
+            info.api_base_url,
+            info.api_port,
+            node
+        );
+
+        app.dialog()
+            .message(message)
+            .title(i18n::t(&app, "dialog.about.title"))
+            .kind(MessageDialogKind::Info)
+            .show(|_| {});
+    });
+}
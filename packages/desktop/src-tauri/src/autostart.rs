@@ -0,0 +1,53 @@
+// ==============================================================================
+// file_id: SOM-SCR-0080-v1.0.0
+// name: autostart.rs
+// description: AEGIS Desktop - Launch at login
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, autostart]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::tray;
+use tauri::{plugin::TauriPlugin, AppHandle, Wry};
+use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
+
+// Passed by the login entry so a boot-time launch goes straight to the tray
+pub const AUTOSTART_ARG: &str = "--autostart";
+
+// Tray checkbox kept in sync when autostart is toggled from the UI
+pub const TRAY_ITEM_ID: &str = "autostart";
+
+// Registers a Run key on Windows, a LaunchAgent on macOS and an XDG autostart entry on Linux
+pub fn plugin() -> TauriPlugin<Wry> {
+    tauri_plugin_autostart::init(MacosLauncher::LaunchAgent, Some(vec![AUTOSTART_ARG]))
+}
+
+pub fn launched_at_login() -> bool {
+    std::env::args().any(|arg| arg == AUTOSTART_ARG)
+}
+
+pub fn is_enabled(app: &AppHandle) -> Result<bool, String> {
+    app.autolaunch().is_enabled().map_err(|e| e.to_string())
+}
+
+pub fn set_enabled(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    let autolaunch = app.autolaunch();
+    let result = if enabled {
+        autolaunch.enable()
+    } else {
+        autolaunch.disable()
+    };
+    result.map_err(|e| format!("Could not update launch at login: {}", e))?;
+    tracing::info!(enabled, "autostart updated");
+
+    sync_menu_item(app);
+    Ok(())
+}
+
+// Reflect the registered state, not the click, so a failed toggle doesn't leave a stale checkmark
+pub fn sync_menu_item(app: &AppHandle) {
+    tray::set_checked(app, TRAY_ITEM_ID, is_enabled(app).unwrap_or(false));
+}
@@ -0,0 +1,104 @@
+// ==============================================================================
+// file_id: SOM-SCR-0103-v1.0.0
+// name: backend_install.rs
+// description: AEGIS Desktop - Checks that the dashboard package is installed before npm runs
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, diagnostics]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ServerMode;
+use crate::server::StartError;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+// What is present in the dashboard directory. `problems` is the same information
+// written out for the user.
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallReport {
+    pub ok: bool,
+    pub dashboard_path: PathBuf,
+    pub dashboard_exists: bool,
+    pub has_package_json: bool,
+    // Server scripts package.json doesn't define; all of them when it can't be parsed
+    pub missing_scripts: Vec<&'static str>,
+    // node_modules exists and isn't empty
+    pub has_node_modules: bool,
+    pub problems: Vec<String>,
+}
+
+impl InstallReport {
+    // Gate for starting the server: only the script for the current mode has to exist
+    pub fn require(&self, script: &'static str) -> Result<(), StartError> {
+        if !self.dashboard_exists || !self.has_package_json {
+            return Err(StartError::InvalidDashboardPath(
+                self.dashboard_path.clone(),
+            ));
+        }
+        if self.missing_scripts.contains(&script) {
+            return Err(StartError::MissingScript {
+                script,
+                package_json: self.dashboard_path.join("package.json"),
+            });
+        }
+        if !self.has_node_modules {
+            return Err(StartError::DependenciesMissing(self.dashboard_path.clone()));
+        }
+        Ok(())
+    }
+}
+
+pub fn inspect(dashboard_path: &Path) -> InstallReport {
+    let dashboard_exists = dashboard_path.is_dir();
+    let package_json = dashboard_path.join("package.json");
+    let has_package_json = package_json.is_file();
+
+    let package = std::fs::read_to_string(&package_json)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok());
+    let missing_scripts: Vec<&'static str> = [ServerMode::Dev, ServerMode::Prod]
+        .into_iter()
+        .map(ServerMode::script)
+        .filter(|script| {
+            !package
+                .as_ref()
+                .is_some_and(|package| package["scripts"][*script].is_string())
+        })
+        .collect();
+
+    let has_node_modules = std::fs::read_dir(dashboard_path.join("node_modules"))
+        .is_ok_and(|mut entries| entries.next().is_some());
+
+    let mut problems = Vec::new();
+    if !dashboard_exists {
+        problems.push(format!("{} does not exist", dashboard_path.display()));
+    } else if !has_package_json {
+        problems.push(format!("{} has no package.json", dashboard_path.display()));
+    } else {
+        if package.is_none() {
+            problems.push(format!("{} is not valid JSON", package_json.display()));
+        }
+        for script in &missing_scripts {
+            problems.push(format!("package.json has no \"{}\" script", script));
+        }
+        if !has_node_modules {
+            problems.push(format!(
+                "Dependencies are not installed; run `npm install` in {}",
+                dashboard_path.display()
+            ));
+        }
+    }
+
+    InstallReport {
+        ok: problems.is_empty(),
+        dashboard_path: dashboard_path.to_path_buf(),
+        dashboard_exists,
+        has_package_json,
+        missing_scripts,
+        has_node_modules,
+        problems,
+    }
+}
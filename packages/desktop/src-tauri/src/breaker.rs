@@ -0,0 +1,307 @@
+// ==============================================================================
+// file_id: SOM-SCR-0112-v1.0.0
+// name: breaker.rs
+// description: AEGIS Desktop - Retries and a circuit breaker for UI health/status calls
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, http, resilience]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::errors::CommandError;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Tries per call before a connection error is returned; the delay doubles after each
+const RETRY_ATTEMPTS: u32 = 3;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+// No retry starts once this long has passed since the first try. Refused connections
+// fail fast and get every attempt; a request that hit its timeout is not tried again,
+// so a call can't hold the UI for several timeouts in a row.
+const RETRY_BUDGET: Duration = Duration::from_secs(1);
+
+// Failed calls in a row (each after all its retries) that open the circuit
+const FAILURE_THRESHOLD: u32 = 3;
+
+// How long an open circuit fails fast before letting one trial call through
+const COOLDOWN: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Circuit {
+    Closed { failures: u32 },
+    // Failing fast until `until`
+    Open { until: Instant },
+    // A trial call went out at `since`; everything else fails fast until it finishes, or
+    // for a cooldown in case it was dropped without finishing
+    HalfOpen { since: Instant },
+}
+
+impl Circuit {
+    // Whether a call may go out at `now`; Err carries how long until the next trial
+    fn admit(&mut self, now: Instant) -> Result<(), Duration> {
+        match *self {
+            Circuit::Closed { .. } => Ok(()),
+            Circuit::Open { until } if now < until => Err(until - now),
+            Circuit::HalfOpen { since } if now < since + COOLDOWN => Err(Duration::ZERO),
+            Circuit::Open { .. } | Circuit::HalfOpen { .. } => {
+                *self = Circuit::HalfOpen { since: now };
+                Ok(())
+            }
+        }
+    }
+
+    fn succeeded(&mut self) {
+        *self = Circuit::Closed { failures: 0 };
+    }
+
+    fn failed(&mut self, now: Instant) {
+        *self = match *self {
+            Circuit::Closed { failures } if failures + 1 < FAILURE_THRESHOLD => Circuit::Closed {
+                failures: failures + 1,
+            },
+            // A failed trial reopens the circuit for another cooldown
+            _ => Circuit::Open {
+                until: now + COOLDOWN,
+            },
+        };
+    }
+}
+
+// The breaker as reported to the UI, e.g. "backend unreachable — retrying in 3s"
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakerStatus {
+    // closed, open or half_open
+    pub state: &'static str,
+    pub consecutive_failures: u32,
+    // Until the next trial call; only set while open
+    pub retry_in_ms: Option<u64>,
+}
+
+// Shared by check_api_health and get_system_status, so either one failing trips both
+pub struct CircuitBreaker {
+    circuit: Mutex<Circuit>,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            circuit: Mutex::new(Circuit::Closed { failures: 0 }),
+        }
+    }
+}
+
+impl CircuitBreaker {
+    pub fn status(&self) -> BreakerStatus {
+        let circuit = *self.lock();
+        match circuit {
+            Circuit::Closed { failures } => BreakerStatus {
+                state: "closed",
+                consecutive_failures: failures,
+                retry_in_ms: None,
+            },
+            Circuit::Open { until } => BreakerStatus {
+                state: "open",
+                consecutive_failures: FAILURE_THRESHOLD,
+                retry_in_ms: Some(
+                    until.saturating_duration_since(Instant::now()).as_millis() as u64
+                ),
+            },
+            Circuit::HalfOpen { .. } => BreakerStatus {
+                state: "half_open",
+                consecutive_failures: FAILURE_THRESHOLD,
+                retry_in_ms: None,
+            },
+        }
+    }
+
+    // Run `request`, retrying connection errors (not running, timed out) with a short
+    // backoff. Any other result, error or not, means the server answered and closes the
+    // circuit. While open, fails fast with NotRunning instead of making a request.
+    pub async fn call<T, F, Fut>(&self, mut request: F) -> Result<T, CommandError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, CommandError>>,
+    {
+        if let Err(wait) = self.lock().admit(Instant::now()) {
+            return Err(CommandError::NotRunning(format!(
+                "Backend unreachable; retrying in {}s",
+                wait.as_secs_f64().ceil() as u64
+            )));
+        }
+
+        let started = Instant::now();
+        let mut delay = RETRY_BASE_DELAY;
+        let mut attempt = 1;
+        let result = loop {
+            let result = request().await;
+            if !is_connection_error(&result) || !retry_allowed(attempt, started.elapsed(), delay) {
+                break result;
+            }
+            tracing::debug!(attempt, "backend request failed to connect; retrying");
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+            attempt += 1;
+        };
+
+        let mut circuit = self.lock();
+        if is_connection_error(&result) {
+            circuit.failed(Instant::now());
+            if let Circuit::Open { .. } = *circuit {
+                tracing::warn!("backend unreachable; circuit breaker open");
+            }
+        } else {
+            if !matches!(*circuit, Circuit::Closed { .. }) {
+                tracing::info!("backend reachable again; circuit breaker closed");
+            }
+            circuit.succeeded();
+        }
+        result
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Circuit> {
+        self.circuit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// Whether another attempt may follow `attempt` after waiting `delay`, `elapsed` into the call
+fn retry_allowed(attempt: u32, elapsed: Duration, delay: Duration) -> bool {
+    attempt < RETRY_ATTEMPTS && elapsed + delay <= RETRY_BUDGET
+}
+
+fn is_connection_error<T>(result: &Result<T, CommandError>) -> bool {
+    matches!(
+        result,
+        Err(CommandError::NotRunning(_) | CommandError::Timeout(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fail_times(circuit: &mut Circuit, times: u32, now: Instant) {
+        for _ in 0..times {
+            circuit.admit(now).unwrap();
+            circuit.failed(now);
+        }
+    }
+
+    #[test]
+    fn opens_after_threshold_failures() {
+        let now = Instant::now();
+        let mut circuit = Circuit::Closed { failures: 0 };
+
+        fail_times(&mut circuit, FAILURE_THRESHOLD - 1, now);
+        assert_eq!(
+            circuit,
+            Circuit::Closed {
+                failures: FAILURE_THRESHOLD - 1
+            }
+        );
+
+        fail_times(&mut circuit, 1, now);
+        assert_eq!(
+            circuit,
+            Circuit::Open {
+                until: now + COOLDOWN
+            }
+        );
+    }
+
+    #[test]
+    fn stays_open_until_cooldown() {
+        let now = Instant::now();
+        let mut circuit = Circuit::Closed { failures: 0 };
+        fail_times(&mut circuit, FAILURE_THRESHOLD, now);
+
+        assert_eq!(circuit.admit(now), Err(COOLDOWN));
+        let later = now + Duration::from_secs(2);
+        assert_eq!(circuit.admit(later), Err(COOLDOWN - Duration::from_secs(2)));
+        assert!(matches!(circuit, Circuit::Open { .. }));
+
+        assert_eq!(circuit.admit(now + COOLDOWN), Ok(()));
+        assert_eq!(
+            circuit,
+            Circuit::HalfOpen {
+                since: now + COOLDOWN
+            }
+        );
+    }
+
+    #[test]
+    fn half_open_admits_one_trial() {
+        let now = Instant::now();
+        let mut circuit = Circuit::Open { until: now };
+
+        assert_eq!(circuit.admit(now), Ok(()));
+        assert_eq!(circuit.admit(now), Err(Duration::ZERO));
+        assert_eq!(
+            circuit.admit(now + COOLDOWN - Duration::from_millis(1)),
+            Err(Duration::ZERO)
+        );
+
+        // A trial that never reported back doesn't keep the circuit shut
+        assert_eq!(circuit.admit(now + COOLDOWN), Ok(()));
+    }
+
+    #[test]
+    fn successful_trial_closes() {
+        let now = Instant::now();
+        let mut circuit = Circuit::Open { until: now };
+        circuit.admit(now).unwrap();
+
+        circuit.succeeded();
+        assert_eq!(circuit, Circuit::Closed { failures: 0 });
+        assert_eq!(circuit.admit(now), Ok(()));
+    }
+
+    #[test]
+    fn failed_trial_reopens() {
+        let now = Instant::now();
+        let mut circuit = Circuit::Open { until: now };
+        circuit.admit(now).unwrap();
+
+        let later = now + Duration::from_secs(1);
+        circuit.failed(later);
+        assert_eq!(
+            circuit,
+            Circuit::Open {
+                until: later + COOLDOWN
+            }
+        );
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let now = Instant::now();
+        let mut circuit = Circuit::Closed { failures: 0 };
+        fail_times(&mut circuit, FAILURE_THRESHOLD - 1, now);
+
+        circuit.succeeded();
+        fail_times(&mut circuit, FAILURE_THRESHOLD - 1, now);
+        assert!(matches!(circuit, Circuit::Closed { .. }));
+    }
+
+    #[test]
+    fn retries_stay_within_budget() {
+        // Refused connections come back at once and get every attempt
+        assert!(retry_allowed(1, Duration::ZERO, RETRY_BASE_DELAY));
+        assert!(retry_allowed(2, RETRY_BASE_DELAY, RETRY_BASE_DELAY * 2));
+        assert!(!retry_allowed(
+            RETRY_ATTEMPTS,
+            Duration::ZERO,
+            RETRY_BASE_DELAY
+        ));
+
+        // A request that ran into its timeout has used up the budget
+        let timed_out = Duration::from_secs(3);
+        assert!(!retry_allowed(1, timed_out, RETRY_BASE_DELAY));
+    }
+}
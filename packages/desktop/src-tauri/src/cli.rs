@@ -0,0 +1,96 @@
+// ==============================================================================
+// file_id: SOM-SCR-0096-v1.0.0
+// name: cli.rs
+// description: AEGIS Desktop - Command-line flags and subcommands for a running instance
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, cli]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::control::{self, ControlCommand, ControlReply, ControlRequest, Endpoint};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::time::Duration;
+
+// Run with only the tray and API server; the main window is created if the user opens it
+pub const HEADLESS_ARG: &str = "--headless";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Long enough for a restart: shutdown grace plus the server's start attempts
+const REPLY_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub fn headless() -> bool {
+    std::env::args().any(|arg| arg == HEADLESS_ARG)
+}
+
+// `aegis status`, `aegis restart` or `aegis stop`
+pub fn subcommand() -> Option<ControlCommand> {
+    std::env::args()
+        .nth(1)
+        .and_then(|arg| ControlCommand::parse(&arg))
+}
+
+// Send a command to the running instance and print its reply. Returns the exit code:
+// 0 on success, 1 when the command failed, 2 when no instance is running.
+pub fn run(command: ControlCommand, identifier: &str) -> i32 {
+    match send(command, identifier) {
+        Ok(Some(reply)) if reply.ok => {
+            println!("{}", reply.output);
+            0
+        }
+        Ok(Some(reply)) => {
+            eprintln!("{}", reply.output);
+            1
+        }
+        Ok(None) => {
+            eprintln!("AEGIS is not running");
+            2
+        }
+        Err(e) => {
+            eprintln!("Could not reach AEGIS: {}", e);
+            1
+        }
+    }
+}
+
+// None when there is no endpoint file or nothing is listening on it
+fn send(command: ControlCommand, identifier: &str) -> Result<Option<ControlReply>, String> {
+    // Same directory Tauri resolves as app_config_dir for this identifier
+    let Some(config_dir) = dirs::config_dir().map(|dir| dir.join(identifier)) else {
+        return Ok(None);
+    };
+    let Ok(contents) = std::fs::read_to_string(control::endpoint_path(&config_dir)) else {
+        return Ok(None);
+    };
+    let endpoint: Endpoint = serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, endpoint.port));
+    let Ok(stream) = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT) else {
+        return Ok(None);
+    };
+    stream
+        .set_read_timeout(Some(REPLY_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+
+    let request = ControlRequest {
+        token: endpoint.token,
+        command,
+    };
+    let mut body = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+    body.push('\n');
+    (&stream)
+        .write_all(body.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let mut line = String::new();
+    BufReader::new(&stream)
+        .read_line(&mut line)
+        .map_err(|e| e.to_string())?;
+    serde_json::from_str(&line)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,480 @@
+// ==============================================================================
+// file_id: SOM-SCR-0070-v1.0.0
+// name: client.rs
+// description: AEGIS Desktop - Shared HTTP client for talking to the API server
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, http]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ApiServerConfig;
+use crate::errors::CommandError;
+use reqwest::{IntoUrl, RequestBuilder, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+use zeroize::{Zeroize, Zeroizing};
+
+// Env var the spawned server reads its expected bearer token from
+pub const API_TOKEN_ENV: &str = "AEGIS_API_TOKEN";
+
+// Sent with health and status requests so a failure can be found in the server's logs
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+// A response from `get_api`, body read in full
+pub struct ApiResponse {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+// One client for every request so connections are pooled and timeouts are uniform.
+// Requests made through `get` carry the per-launch API token as a bearer header.
+//
+// Sensitive: `token`. It is zeroed on drop, wiped in place on quit and lockdown (the process
+// exits without running destructors) and replaced with a fresh one on unlock.
+pub struct ApiClient {
+    // Replaced by `rebuild`; reqwest clients are cheap handles onto a shared pool
+    http: RwLock<reqwest::Client>,
+    pub timeout: Duration,
+    // Generated at startup and only ever held in memory
+    token: Mutex<Zeroizing<String>>,
+}
+
+impl ApiClient {
+    // Client with the configured timeouts, CA and proxy. Fails when the CA file can't be
+    // loaded, proxy_url is unusable or reqwest rejects the options.
+    pub fn build(config: &ApiServerConfig) -> Result<Self, String> {
+        let http = http_client(config)?;
+        Ok(Self::with_http(http, config.request_timeout()))
+    }
+
+    // Used when `build` fails: same timeouts, system roots and proxy only. Servers signed by
+    // the missing CA fail their requests instead of taking the app down.
+    pub fn fallback(config: &ApiServerConfig) -> Self {
+        let timeout = config.request_timeout();
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(timeout)
+            .timeout(timeout);
+        if config.is_local() {
+            builder = builder.no_proxy();
+        }
+        Self::with_http(builder.build().unwrap_or_default(), timeout)
+    }
+
+    // `build`, or `fallback` with a warning when it fails. The error is handed back so
+    // setup can tell the user what to fix.
+    pub fn build_or_fallback(config: &ApiServerConfig) -> (Self, Option<String>) {
+        match Self::build(config) {
+            Ok(client) => (client, None),
+            Err(e) => {
+                tracing::warn!(error = %e, "falling back to the default http client");
+                (Self::fallback(config), Some(e))
+            }
+        }
+    }
+
+    fn with_http(http: reqwest::Client, timeout: Duration) -> Self {
+        Self {
+            http: RwLock::new(http),
+            timeout,
+            token: Mutex::new(generate_token()),
+        }
+    }
+
+    // Swap in a client built from `config`, e.g. after the proxy changed. The token and
+    // timeout stay as they are; on error the current client is kept.
+    pub fn rebuild(&self, config: &ApiServerConfig) -> Result<(), String> {
+        let http = http_client(config)?;
+        *self.http.write().map_err(|e| e.to_string())? = http;
+        Ok(())
+    }
+
+    // GET with the bearer token; reqwest marks the header sensitive so it stays out of Debug output
+    pub fn get(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.authorized(|http| http.get(url))
+    }
+
+    fn authorized(
+        &self,
+        request: impl FnOnce(&reqwest::Client) -> RequestBuilder,
+    ) -> RequestBuilder {
+        let http = self
+            .http
+            .read()
+            .map(|http| http.clone())
+            .unwrap_or_default();
+        request(&http).bearer_auth(self.token().as_str())
+    }
+
+    // `get` tagged with a request ID from `new_request_id`
+    pub fn get_traced(&self, url: impl IntoUrl, request_id: &str) -> RequestBuilder {
+        self.get(url).header(REQUEST_ID_HEADER, request_id)
+    }
+
+    // GET an API path with the token and a request ID: over api.socket_path when one is
+    // set (Unix only), otherwise over TCP to the base URL
+    pub async fn get_api(
+        &self,
+        config: &ApiServerConfig,
+        path: &str,
+        request_id: &str,
+    ) -> Result<ApiResponse, CommandError> {
+        self.send_api(config, path, None, request_id).await
+    }
+
+    // POST a JSON body to an API path, the same way as `get_api`
+    pub async fn post_api(
+        &self,
+        config: &ApiServerConfig,
+        path: &str,
+        body: &Value,
+        request_id: &str,
+    ) -> Result<ApiResponse, CommandError> {
+        self.send_api(config, path, Some(body), request_id).await
+    }
+
+    // GET without a body, POST with one
+    async fn send_api(
+        &self,
+        config: &ApiServerConfig,
+        path: &str,
+        json: Option<&Value>,
+        request_id: &str,
+    ) -> Result<ApiResponse, CommandError> {
+        #[cfg(unix)]
+        if let Some(socket) = config.unix_socket() {
+            use crate::unix_socket::{self, SocketRequest};
+            use hyper::Method;
+
+            let token = self.token();
+            let request = SocketRequest {
+                method: if json.is_some() {
+                    Method::POST
+                } else {
+                    Method::GET
+                },
+                path,
+                json: json.map(Value::to_string),
+                token: &token,
+                request_id,
+            };
+            let (status, body) = unix_socket::send(socket, request, self.timeout).await?;
+            return Ok(ApiResponse { status, body });
+        }
+
+        let url = config
+            .api_url(path)
+            .map_err(|e| CommandError::Config(format!("Invalid API URL: {}", e)))?;
+        let request = match json {
+            Some(json) => self
+                .authorized(|http| http.post(url))
+                .header(REQUEST_ID_HEADER, request_id)
+                .json(json),
+            None => self.get_traced(url, request_id),
+        };
+        let response = request.send().await.map_err(|e| self.command_error(&e))?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| self.command_error(&e))?;
+        Ok(ApiResponse { status, body })
+    }
+
+    // A copy that is zeroed when the caller drops it. Empty after `wipe_token`.
+    pub fn token(&self) -> Zeroizing<String> {
+        self.token
+            .lock()
+            .map(|token| token.clone())
+            .unwrap_or_default()
+    }
+
+    // Overwrite the token in memory. Servers started afterwards get an empty one
+    // until `rotate_token` runs.
+    pub fn wipe_token(&self) {
+        if let Ok(mut token) = self.token.lock() {
+            token.zeroize();
+        }
+    }
+
+    // Replace the token with a new one; the old value is zeroed as it is dropped
+    pub fn rotate_token(&self) {
+        if let Ok(mut token) = self.token.lock() {
+            *token = generate_token();
+        }
+    }
+
+    // Error text for a failed request, spelling out timeouts for the UI
+    pub fn describe_error(&self, error: &reqwest::Error) -> String {
+        if error.is_timeout() {
+            format!("API server timed out after {}ms", self.timeout.as_millis())
+        } else {
+            error.to_string()
+        }
+    }
+
+    // describe_error, classified for commands that return a CommandError
+    pub fn command_error(&self, error: &reqwest::Error) -> CommandError {
+        let message = self.describe_error(error);
+        if error.is_timeout() {
+            CommandError::Timeout(message)
+        } else if error.is_connect() {
+            CommandError::NotRunning(message)
+        } else {
+            CommandError::Backend(message)
+        }
+    }
+}
+
+// A UUID per request, logged on our side and quoted in errors
+pub fn new_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+// 256 random bits, hex encoded
+pub fn generate_token() -> Zeroizing<String> {
+    let mut bytes = Zeroizing::new(rand::random::<[u8; 32]>());
+    let token = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    bytes.zeroize();
+    Zeroizing::new(token)
+}
+
+// What the client does about proxies, for get_proxy
+#[derive(Debug, Clone, Serialize)]
+pub struct ProxySettings {
+    // api.proxy_url, used instead of the system proxy
+    pub proxy_url: Option<String>,
+    // HTTPS_PROXY / HTTP_PROXY / ALL_PROXY as reqwest reads them; macOS and Windows
+    // system settings apply as well when none of these are set
+    pub env_proxy: Option<String>,
+    // The base URL is local, so no proxy is used at all
+    pub bypassed: bool,
+}
+
+pub fn proxy_settings(config: &ApiServerConfig) -> ProxySettings {
+    let env_proxy = [
+        "HTTPS_PROXY",
+        "https_proxy",
+        "HTTP_PROXY",
+        "http_proxy",
+        "ALL_PROXY",
+        "all_proxy",
+    ]
+    .into_iter()
+    .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()));
+    ProxySettings {
+        proxy_url: config.proxy_url.clone(),
+        env_proxy,
+        bypassed: config.is_local(),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ProxyRoute<'a> {
+    Direct,
+    Configured(&'a str),
+    System,
+}
+
+// reqwest follows the system proxy by default. Local traffic skips any proxy, and
+// proxy_url replaces the system one for everything else.
+fn proxy_route(config: &ApiServerConfig) -> ProxyRoute<'_> {
+    if config.is_local() {
+        ProxyRoute::Direct
+    } else if let Some(proxy_url) = &config.proxy_url {
+        ProxyRoute::Configured(proxy_url)
+    } else {
+        ProxyRoute::System
+    }
+}
+
+// Timeouts, CA and proxy from config; shared by build and rebuild
+fn http_client(config: &ApiServerConfig) -> Result<reqwest::Client, String> {
+    let timeout = config.request_timeout();
+    let mut builder = reqwest::Client::builder()
+        .connect_timeout(timeout)
+        .timeout(timeout);
+
+    // Certificate validation stays on; a user CA is added on top of the system roots
+    if config.uses_https() {
+        if let Some(path) = &config.ca_cert_path {
+            let certificate = load_certificate(path).map_err(|e| {
+                format!(
+                    "Could not load the CA certificate {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    match proxy_route(config) {
+        ProxyRoute::Direct => builder = builder.no_proxy(),
+        ProxyRoute::Configured(proxy_url) => {
+            let proxy = reqwest::Proxy::all(proxy_url)
+                .map_err(|e| format!("Could not use proxy {}: {}", proxy_url, e))?;
+            builder = builder.proxy(proxy);
+        }
+        ProxyRoute::System => {}
+    }
+
+    builder
+        .build()
+        .map_err(|e| format!("Could not set up the HTTP client: {}", e))
+}
+
+fn load_certificate(path: &Path) -> Result<reqwest::Certificate, String> {
+    let pem = std::fs::read(path).map_err(|e| e.to_string())?;
+    reqwest::Certificate::from_pem(&pem).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use zeroize::ZeroizeOnDrop;
+
+    fn zeroed_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+    fn local_client() -> ApiClient {
+        ApiClient::build(&ApiServerConfig::default()).expect("default config builds")
+    }
+
+    fn https_with_ca(ca_cert_path: PathBuf) -> ApiServerConfig {
+        ApiServerConfig {
+            api_base_url: Some("https://aegis.example.com".into()),
+            ca_cert_path: Some(ca_cert_path),
+            ..ApiServerConfig::default()
+        }
+    }
+
+    fn assert_falls_back(config: &ApiServerConfig) {
+        let (client, error) = ApiClient::build_or_fallback(config);
+        let error = error.expect("a bad CA should be reported");
+        assert!(
+            error.contains("Could not load the CA certificate"),
+            "{}",
+            error
+        );
+        // The fallback still makes requests, with the token
+        let request = client.get("https://aegis.example.com/api/health").build();
+        assert!(request.unwrap().headers().contains_key("authorization"));
+    }
+
+    #[test]
+    fn missing_ca_file_falls_back() {
+        let missing = std::env::temp_dir().join("aegis-test-missing-ca.pem");
+        let _ = std::fs::remove_file(&missing);
+        assert_falls_back(&https_with_ca(missing));
+    }
+
+    #[test]
+    fn invalid_ca_file_falls_back() {
+        let invalid =
+            std::env::temp_dir().join(format!("aegis-test-{}-ca.pem", std::process::id()));
+        std::fs::write(&invalid, "not a certificate").unwrap();
+        let config = https_with_ca(invalid.clone());
+        assert_falls_back(&config);
+        let _ = std::fs::remove_file(invalid);
+    }
+
+    #[test]
+    fn valid_config_builds_without_fallback() {
+        let (_, error) = ApiClient::build_or_fallback(&ApiServerConfig::default());
+        assert!(error.is_none());
+    }
+
+    fn proxied(base_url: &str) -> ApiServerConfig {
+        ApiServerConfig {
+            api_base_url: Some(base_url.into()),
+            proxy_url: Some("http://proxy.example.com:3128".into()),
+            ..ApiServerConfig::default()
+        }
+    }
+
+    #[test]
+    fn local_base_urls_ignore_proxy_url() {
+        for base_url in [
+            "http://localhost:4243",
+            "http://LOCALHOST:4243",
+            "http://127.0.0.1:4243",
+            "https://127.0.0.1:4243",
+            "http://[::1]:4243",
+        ] {
+            assert_eq!(
+                proxy_route(&proxied(base_url)),
+                ProxyRoute::Direct,
+                "{}",
+                base_url
+            );
+        }
+    }
+
+    #[test]
+    fn remote_base_urls_use_proxy_url() {
+        assert_eq!(
+            proxy_route(&proxied("https://aegis.example.com")),
+            ProxyRoute::Configured("http://proxy.example.com:3128")
+        );
+
+        let system = ApiServerConfig {
+            proxy_url: None,
+            ..proxied("https://aegis.example.com")
+        };
+        assert_eq!(proxy_route(&system), ProxyRoute::System);
+    }
+
+    #[test]
+    fn token_is_zeroed_on_drop() {
+        let client = local_client();
+        // Both the stored token and the copies handed out are wiped when dropped
+        zeroed_on_drop(&*client.token.lock().unwrap());
+        let token = client.token();
+        zeroed_on_drop(&token);
+        assert_eq!(token.len(), 64);
+    }
+
+    #[test]
+    fn token_stays_out_of_debug_output() {
+        let client = local_client();
+        let token = client.token();
+        let request = client
+            .get("http://localhost:4243/api/health")
+            .build()
+            .unwrap();
+
+        let debug = format!("{:?}", request);
+        assert!(!debug.contains(token.as_str()));
+        assert!(request.headers()["authorization"].is_sensitive());
+    }
+
+    #[test]
+    fn wipe_token_clears_it_in_place() {
+        let client = local_client();
+        client.wipe_token();
+        assert!(client.token().is_empty());
+    }
+
+    #[test]
+    fn rotate_token_replaces_it() {
+        let client = local_client();
+        let before = client.token();
+        client.rotate_token();
+        let after = client.token();
+        assert_ne!(before, after);
+        assert_eq!(after.len(), 64);
+    }
+
+    #[test]
+    fn rebuild_keeps_the_running_servers_token() {
+        // The spawned server was given the token in its environment; a new proxy or CA
+        // must not lock the app out of it
+        let client = local_client();
+        let before = client.token();
+        client.rebuild(&ApiServerConfig::default()).unwrap();
+        assert_eq!(client.token(), before);
+    }
+}
@@ -0,0 +1,71 @@
+// ==============================================================================
+// file_id: SOM-SCR-0098-v1.0.0
+// name: clipboard.rs
+// description: AEGIS Desktop - Copying secrets with an optional timed clipboard wipe
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, clipboard, privacy]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use zeroize::Zeroizing;
+
+// Bumped on every sensitive copy so only the latest one's timer may clear
+#[derive(Default)]
+pub struct ClipboardState {
+    generation: AtomicU64,
+}
+
+// Put a secret on the clipboard, scheduling a wipe when auto-clear is turned on
+pub fn copy_sensitive(app: &AppHandle, text: String) -> Result<(), String> {
+    app.clipboard()
+        .write_text(text.as_str())
+        .map_err(|e| format!("Could not copy to the clipboard: {}", e))?;
+
+    let preferences = app.state::<ConfigStore>().preferences();
+    if preferences.clipboard_auto_clear {
+        clear_clipboard_after(app, text, preferences.clipboard_clear_secs);
+    }
+    Ok(())
+}
+
+// Clear the clipboard after `secs`, unless something else has been copied since
+pub fn clear_clipboard_after(app: &AppHandle, text: String, secs: u64) {
+    let generation = app
+        .state::<ClipboardState>()
+        .generation
+        .fetch_add(1, Ordering::SeqCst)
+        + 1;
+    // Sensitive: the copied secret is zeroed once the timer has run
+    let text = Zeroizing::new(text);
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(secs)).await;
+
+        // A newer sensitive copy owns the clipboard and its own timer
+        if app
+            .state::<ClipboardState>()
+            .generation
+            .load(Ordering::SeqCst)
+            != generation
+        {
+            return;
+        }
+        let unchanged = app
+            .clipboard()
+            .read_text()
+            .is_ok_and(|current| current == *text);
+        if unchanged {
+            let _ = app.clipboard().clear();
+            tracing::debug!(secs, "cleared sensitive clipboard contents");
+        }
+    });
+}
@@ -0,0 +1,719 @@
+// ==============================================================================
+// file_id: SOM-SCR-0063-v1.0.0
+// name: config.rs
+// description: AEGIS Desktop - Application configuration loaded from aegis.toml
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, config]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config_crypto;
+use crate::exit_hook::ExitHook;
+use crate::preferences::Preferences;
+use crate::wsl;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::Duration;
+use zeroize::Zeroizing;
+
+pub const DEFAULT_API_PORT: u16 = 4243;
+
+// Env var read on startup and passed through to the spawned Node server
+pub const API_PORT_ENV: &str = "AEGIS_API_PORT";
+
+// Set for the spawned Node server when api.socket_path is in use, so it listens there
+// instead of on the port
+pub const API_SOCKET_ENV: &str = "AEGIS_API_SOCKET";
+
+// Log level for the spawned server, from server_env or set_backend_log_level
+pub const LOG_LEVEL_ENV: &str = "LOG_LEVEL";
+
+// Where node_flags reach the server; npm passes it on to node
+pub const NODE_OPTIONS_ENV: &str = "NODE_OPTIONS";
+
+const CONFIG_FILE_NAME: &str = "aegis.toml";
+
+// Longest Unix socket path every supported platform accepts, plus one
+const SOCKET_PATH_MAX: usize = 104;
+
+// API fields the running app has already acted on (server port, HTTP client);
+// saved changes to them only take effect after a restart
+const RESTART_REQUIRED_FIELDS: [&str; 8] = [
+    "port",
+    "socket_path",
+    "backend_transport",
+    "wsl_distro",
+    "api_base_url",
+    "ca_cert_path",
+    "request_timeout_ms",
+    "proxy_url",
+];
+
+// Which npm script runs the API server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerMode {
+    // `dev:server`, which runs the TypeScript sources and reloads on change
+    Dev,
+    // `start:server`, the built server
+    Prod,
+}
+
+impl Default for ServerMode {
+    // Debug builds run next to the sources; release builds ship the built server
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            Self::Dev
+        } else {
+            Self::Prod
+        }
+    }
+}
+
+impl ServerMode {
+    pub fn script(self) -> &'static str {
+        match self {
+            Self::Dev => "dev:server",
+            Self::Prod => "start:server",
+        }
+    }
+}
+
+// Where the API server runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendTransport {
+    // Node.js on this machine
+    #[default]
+    Native,
+    // Windows only: `npm` inside a WSL distro, reached through WSL's localhost forwarding
+    Wsl,
+}
+
+// Settings for the managed Express API server
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiServerConfig {
+    pub port: u16,
+    // Spawn/readiness attempts before giving up; delays double from the base each time
+    pub start_attempts: u32,
+    pub start_retry_base_ms: u64,
+    // Crash-loop guard for the watchdog
+    pub max_restarts_per_minute: u32,
+    // How long stop_api_server waits for a clean exit before killing the server
+    pub shutdown_grace_ms: u64,
+    // Connect + read timeout for health and status requests
+    pub request_timeout_ms: u64,
+    // Longest the launch splash waits for a healthy backend before showing the dashboard anyway
+    pub splash_timeout_ms: u64,
+    // Seconds between background health checks for the tray; 0 turns polling off
+    pub health_poll_interval_secs: u64,
+    // Each health poll sleep is randomized by up to this fraction of the interval either
+    // way, so instances sharing a backend don't poll in lockstep. 0 keeps it fixed.
+    pub health_poll_jitter: f64,
+    // Unix only: serve the API on this socket instead of the TCP port, so no other network
+    // client can reach it. Health and status requests go over it too. Ignored on Windows.
+    pub socket_path: Option<PathBuf>,
+    // native, or wsl to run the server inside WSL (Windows only)
+    pub backend_transport: BackendTransport,
+    // WSL distro for the wsl transport; None uses the default distro
+    pub wsl_distro: Option<String>,
+    // Where health/status requests go; defaults to http://localhost:<port>.
+    // Useful when the dashboard server sits behind a local TLS proxy or on another host.
+    pub api_base_url: Option<String>,
+    // Extra PEM root certificate trusted for an https base URL
+    pub ca_cert_path: Option<PathBuf>,
+    // Proxy for a remote base URL, overriding the system proxy. A local base URL is never proxied.
+    pub proxy_url: Option<String>,
+    // Directory the server is run from; defaults to packages/dashboard in the monorepo
+    pub dashboard_path: Option<PathBuf>,
+    // dev or prod; unset follows the build (dev for debug builds, prod for release)
+    pub server_mode: Option<ServerMode>,
+    // Extra environment variables for the spawned server. AEGIS_API_PORT always follows `port`.
+    pub server_env: BTreeMap<String, String>,
+    // Node.js flags such as `--max-old-space-size=2048`, passed to the server in NODE_OPTIONS
+    pub node_flags: Vec<String>,
+    // Release feed for update checks; defaults to the GitHub releases manifest
+    pub update_feed_url: Option<String>,
+    // Minisign public key for a self-hosted feed, overriding tauri.conf.json
+    pub update_pubkey: Option<String>,
+    // Backend version this app works with; defaults to the app's own version. Only the
+    // major version has to match.
+    pub expected_backend_version: Option<String>,
+    // App resource directory, checked for a bundled Node.js. Filled in at startup, never read from disk.
+    #[serde(skip)]
+    pub resource_dir: Option<PathBuf>,
+    // LOG_LEVEL chosen with set_backend_log_level, overriding server_env until the app quits
+    #[serde(skip)]
+    pub log_level: Option<String>,
+}
+
+impl Default for ApiServerConfig {
+    fn default() -> Self {
+        Self {
+            port: DEFAULT_API_PORT,
+            start_attempts: 5,
+            start_retry_base_ms: 250,
+            max_restarts_per_minute: 3,
+            shutdown_grace_ms: 5000,
+            request_timeout_ms: 3000,
+            splash_timeout_ms: 15000,
+            health_poll_interval_secs: 5,
+            health_poll_jitter: 0.2,
+            socket_path: None,
+            backend_transport: BackendTransport::Native,
+            wsl_distro: None,
+            api_base_url: None,
+            ca_cert_path: None,
+            proxy_url: None,
+            dashboard_path: None,
+            server_mode: None,
+            server_env: BTreeMap::new(),
+            node_flags: Vec::new(),
+            update_feed_url: None,
+            update_pubkey: None,
+            expected_backend_version: None,
+            resource_dir: None,
+            log_level: None,
+        }
+    }
+}
+
+impl ApiServerConfig {
+    pub fn shutdown_grace(&self) -> Duration {
+        Duration::from_millis(self.shutdown_grace_ms)
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    pub fn splash_timeout(&self) -> Duration {
+        Duration::from_millis(self.splash_timeout_ms)
+    }
+
+    // None when polling is disabled
+    pub fn health_poll_interval(&self) -> Option<Duration> {
+        (self.health_poll_interval_secs > 0)
+            .then(|| Duration::from_secs(self.health_poll_interval_secs))
+    }
+
+    pub fn server_mode(&self) -> ServerMode {
+        self.server_mode.unwrap_or_default()
+    }
+
+    // NODE_OPTIONS for the server: server_env's own value followed by node_flags
+    pub fn node_options(&self) -> Option<String> {
+        let options: Vec<&str> = self
+            .server_env
+            .get(NODE_OPTIONS_ENV)
+            .map(String::as_str)
+            .into_iter()
+            .chain(self.node_flags.iter().map(String::as_str))
+            .collect();
+        (!options.is_empty()).then(|| options.join(" "))
+    }
+
+    pub fn base_url(&self) -> String {
+        if let Some(base_url) = &self.api_base_url {
+            return base_url.clone();
+        }
+        let host = match self.backend_transport {
+            BackendTransport::Native => "localhost".to_string(),
+            BackendTransport::Wsl => wsl::host(self.wsl_distro.as_deref()),
+        };
+        format!("http://{}:{}", host, self.port)
+    }
+
+    // api.socket_path on platforms with Unix sockets; None means TCP
+    pub fn unix_socket(&self) -> Option<&Path> {
+        if cfg!(unix) {
+            self.socket_path.as_deref()
+        } else {
+            None
+        }
+    }
+
+    // Where requests actually go, for messages: the socket path or the base URL
+    pub fn endpoint(&self) -> String {
+        match self.unix_socket() {
+            Some(socket) => socket.display().to_string(),
+            None => self.base_url(),
+        }
+    }
+
+    pub fn uses_https(&self) -> bool {
+        Url::parse(&self.base_url()).is_ok_and(|url| url.scheme() == "https")
+    }
+
+    // The base URL is localhost or a loopback address
+    pub fn is_local(&self) -> bool {
+        let Ok(url) = Url::parse(&self.base_url()) else {
+            return false;
+        };
+        match url.host_str() {
+            Some(host) if host.eq_ignore_ascii_case("localhost") => true,
+            Some(host) => host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback()),
+            None => false,
+        }
+    }
+
+    // Build a full URL for an API path such as `/api/health`.
+    // The base is treated as a directory so `https://host/aegis` keeps its `/aegis` prefix.
+    pub fn api_url(&self, path: &str) -> Result<Url, String> {
+        let mut base = Url::parse(&self.base_url()).map_err(|e| e.to_string())?;
+        if !base.path().ends_with('/') {
+            let dir = format!("{}/", base.path());
+            base.set_path(&dir);
+        }
+        base.join(path.trim_start_matches('/'))
+            .map_err(|e| e.to_string())
+    }
+}
+
+// A named backend such as "work" or "personal", stored as `[profiles.<name>]`.
+// Fields that are set override `[api]` while the profile is active.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackendProfile {
+    pub port: Option<u16>,
+    pub api_base_url: Option<String>,
+    pub dashboard_path: Option<PathBuf>,
+}
+
+impl BackendProfile {
+    fn apply(&self, api: &mut ApiServerConfig) {
+        if let Some(port) = self.port {
+            api.port = port;
+        }
+        if let Some(base_url) = &self.api_base_url {
+            api.api_base_url = Some(base_url.clone());
+        }
+        if let Some(dashboard_path) = &self.dashboard_path {
+            api.dashboard_path = Some(dashboard_path.clone());
+        }
+    }
+}
+
+// Everything stored in aegis.toml, as `[api]`, `[preferences]` and `[profiles.*]` tables
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    // Store aegis.toml encrypted with a key from the OS keychain. When the keychain can't
+    // be reached the file is written in plaintext (with this flag still set) and a warning logged.
+    pub encrypt_at_rest: bool,
+    // Profile in use; unset runs the plain `[api]` settings
+    pub active_profile: Option<String>,
+    pub api: ApiServerConfig,
+    pub preferences: Preferences,
+    pub profiles: BTreeMap<String, BackendProfile>,
+    // Opt-in cleanup program run on quit, after the API server has stopped
+    pub on_exit: Option<ExitHook>,
+}
+
+impl Config {
+    // `[api]` with the active profile's overrides applied
+    pub fn effective_api(&self) -> ApiServerConfig {
+        let mut api = self.api.clone();
+        if let Some(profile) = self
+            .active_profile
+            .as_ref()
+            .and_then(|name| self.profiles.get(name))
+        {
+            profile.apply(&mut api);
+        }
+        api
+    }
+
+    // Reject values the app can't run with; checked on load and before every write
+    pub fn validate(&self) -> Result<(), String> {
+        validate_api(&self.api, "api")?;
+        for (name, profile) in &self.profiles {
+            if name.trim().is_empty() {
+                return Err("profile names must not be empty".into());
+            }
+            let mut api = self.api.clone();
+            profile.apply(&mut api);
+            validate_api(&api, &format!("profiles.{}", name))?;
+        }
+        if let Some(name) = &self.active_profile {
+            if !self.profiles.contains_key(name) {
+                return Err(format!(
+                    "active_profile {:?} has no [profiles.{}] table",
+                    name, name
+                ));
+            }
+        }
+
+        let preferences = &self.preferences;
+        if preferences.toggle_hotkey.trim().is_empty() {
+            return Err("preferences.toggle_hotkey must not be empty".into());
+        }
+        if preferences.lockdown_hotkey.trim().is_empty() {
+            return Err("preferences.lockdown_hotkey must not be empty".into());
+        }
+        if preferences.clipboard_clear_secs == 0 {
+            return Err("preferences.clipboard_clear_secs must be at least 1".into());
+        }
+        if let Some(hook) = &self.on_exit {
+            hook.validate()?;
+        }
+        Ok(())
+    }
+}
+
+// Checks for one `[api]`-shaped table; `table` prefixes the field names in errors
+fn validate_api(api: &ApiServerConfig, table: &str) -> Result<(), String> {
+    if api.port < 1024 {
+        return Err(format!(
+            "{}.port must be between 1024 and 65535, got {}",
+            table, api.port
+        ));
+    }
+    if api.start_attempts == 0 {
+        return Err(format!("{}.start_attempts must be at least 1", table));
+    }
+    if api.request_timeout_ms == 0 {
+        return Err(format!(
+            "{}.request_timeout_ms must be greater than 0",
+            table
+        ));
+    }
+    if api.backend_transport == BackendTransport::Wsl && !cfg!(windows) {
+        return Err(format!(
+            "{}.backend_transport = \"wsl\" is only supported on Windows",
+            table
+        ));
+    }
+    if let Some(distro) = &api.wsl_distro {
+        if distro.is_empty() || distro.chars().any(char::is_whitespace) {
+            return Err(format!(
+                "{}.wsl_distro must be a distro name without spaces, got {:?}",
+                table, distro
+            ));
+        }
+    }
+    if !(0.0..1.0).contains(&api.health_poll_jitter) {
+        return Err(format!(
+            "{}.health_poll_jitter must be at least 0 and below 1, got {}",
+            table, api.health_poll_jitter
+        ));
+    }
+    if let Some(socket) = &api.socket_path {
+        if !socket.is_absolute() {
+            return Err(format!("{}.socket_path must be an absolute path", table));
+        }
+        // sun_path holds 104 bytes on macOS and 108 on Linux, including the terminator
+        if socket.as_os_str().len() >= SOCKET_PATH_MAX {
+            return Err(format!(
+                "{}.socket_path must be shorter than {} bytes",
+                table, SOCKET_PATH_MAX
+            ));
+        }
+    }
+    if let Some(base_url) = &api.api_base_url {
+        let url = Url::parse(base_url)
+            .map_err(|e| format!("{}.api_base_url is not a valid URL: {}", table, e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!(
+                "{}.api_base_url must be http or https, got {}",
+                table,
+                url.scheme()
+            ));
+        }
+    }
+    for flag in &api.node_flags {
+        validate_node_flag(flag).map_err(|e| format!("{}.node_flags: {}", table, e))?;
+    }
+    if let Some(proxy_url) = &api.proxy_url {
+        Url::parse(proxy_url)
+            .map_err(|e| format!("{}.proxy_url is not a valid URL: {}", table, e))?;
+    }
+    if let Some(feed_url) = &api.update_feed_url {
+        Url::parse(feed_url)
+            .map_err(|e| format!("{}.update_feed_url is not a valid URL: {}", table, e))?;
+    }
+    Ok(())
+}
+
+// `--name` or `--name=value` with plain characters only. NODE_OPTIONS is split on spaces,
+// so anything looser could smuggle in extra flags such as --require.
+fn validate_node_flag(flag: &str) -> Result<(), String> {
+    let (name, value) = match flag.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (flag, None),
+    };
+    let valid_name = name.strip_prefix("--").is_some_and(|name| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    });
+    let valid_value = value.is_none_or(|value| {
+        !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+    });
+    if valid_name && valid_value {
+        Ok(())
+    } else {
+        Err(format!(
+            "\"{}\" is not allowed; use --flag or --flag=value with letters, digits, '.', '_' or '-'",
+            flag
+        ))
+    }
+}
+
+// Managed config: `saved` mirrors aegis.toml, `active` is what the running app uses
+pub struct ConfigStore {
+    path: Option<PathBuf>,
+    saved: RwLock<Config>,
+    active: RwLock<Config>,
+}
+
+impl ConfigStore {
+    // Load aegis.toml from the config dir, creating it with defaults on first run.
+    // A file that fails to parse or validate is left untouched and defaults are used.
+    pub fn load(config_dir: Option<&Path>, resource_dir: Option<PathBuf>) -> Self {
+        let path = config_dir.map(|dir| dir.join(CONFIG_FILE_NAME));
+
+        let saved = match &path {
+            Some(path) if path.exists() => read_config(path).unwrap_or_else(|e| {
+                tracing::warn!(path = %path.display(), error = %e, "invalid config, using defaults");
+                preserve_unreadable(path);
+                Config::default()
+            }),
+            Some(path) => {
+                let config = Config::default();
+                if let Err(e) = write_config(path, &config) {
+                    tracing::warn!(path = %path.display(), error = %e, "could not create config file");
+                }
+                config
+            }
+            None => Config::default(),
+        };
+
+        let mut active = saved.clone();
+        active.api = saved.effective_api();
+        active.api.resource_dir = resource_dir;
+        if let Some(port) = std::env::var(API_PORT_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse::<u16>().ok())
+        {
+            active.api.port = port;
+        }
+
+        Self {
+            path,
+            saved: RwLock::new(saved),
+            active: RwLock::new(active),
+        }
+    }
+
+    // The config as written in aegis.toml
+    pub fn get(&self) -> Config {
+        self.saved
+            .read()
+            .map(|config| config.clone())
+            .unwrap_or_default()
+    }
+
+    // API settings in effect, including the active profile, env overrides and the resource dir
+    pub fn api(&self) -> ApiServerConfig {
+        self.active
+            .read()
+            .map(|config| config.api.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn preferences(&self) -> Preferences {
+        self.active
+            .read()
+            .map(|config| config.preferences.clone())
+            .unwrap_or_default()
+    }
+
+    // Apply a change, validate it and write it to disk. Returns the changed
+    // fields that only take effect after AEGIS is restarted.
+    pub fn update(&self, change: impl FnOnce(&mut Config)) -> Result<Vec<&'static str>, String> {
+        let mut saved = self.saved.write().map_err(|e| e.to_string())?;
+        let mut updated = saved.clone();
+        change(&mut updated);
+        updated.validate()?;
+
+        if let Some(path) = &self.path {
+            write_config(path, &updated)?;
+        }
+        self.swap(&mut saved, updated)
+    }
+
+    // Copy aegis.toml to aegis.toml.bak and replace it with the defaults. Like any other edit,
+    // returns the restart-required fields, which keep their running values until then.
+    pub fn reset(&self) -> Result<Vec<&'static str>, String> {
+        let mut saved = self.saved.write().map_err(|e| e.to_string())?;
+        let defaults = Config::default();
+        if let Some(path) = &self.path {
+            if path.exists() {
+                let backup = path.with_extension("toml.bak");
+                std::fs::copy(path, &backup)
+                    .map_err(|e| format!("Could not back up {}: {}", path.display(), e))?;
+                tracing::info!(backup = %backup.display(), "config backed up before reset");
+            }
+            write_config(path, &defaults)?;
+        }
+        self.swap(&mut saved, defaults)
+    }
+
+    // Re-read aegis.toml after it changed on disk. Returns None when the file matches
+    // what is loaded (such as after our own writes), otherwise the fields that need a restart.
+    pub fn reload(&self) -> Result<Option<Vec<&'static str>>, String> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        let updated = read_config(path)?;
+
+        let mut saved = self.saved.write().map_err(|e| e.to_string())?;
+        if *saved == updated {
+            return Ok(None);
+        }
+        self.swap(&mut saved, updated).map(Some)
+    }
+
+    // Save a new active profile (None for plain `[api]`). Unlike other edits, its port and base
+    // URL apply right away, so the caller must restart the API server afterwards.
+    pub fn switch_profile(&self, name: Option<String>) -> Result<ApiServerConfig, String> {
+        self.update(|config| config.active_profile = name)?;
+        let effective = self.get().effective_api();
+
+        let mut active = self.active.write().map_err(|e| e.to_string())?;
+        active.api.port = effective.port;
+        active.api.api_base_url = effective.api_base_url;
+        active.api.dashboard_path = effective.dashboard_path;
+        Ok(active.api.clone())
+    }
+
+    // Save a proxy override (None for the system proxy) and apply it right away, unlike
+    // other edits to proxy_url. The caller rebuilds the HTTP client from the returned config.
+    pub fn set_proxy_url(&self, proxy_url: Option<String>) -> Result<ApiServerConfig, String> {
+        self.update(|config| config.api.proxy_url = proxy_url.clone())?;
+        let mut active = self.active.write().map_err(|e| e.to_string())?;
+        active.api.proxy_url = proxy_url;
+        Ok(active.api.clone())
+    }
+
+    // Set the backend's log level for this run only (None for server_env's). Nothing is
+    // saved; servers spawned from now on get it in LOG_LEVEL.
+    pub fn set_log_level(&self, level: Option<String>) -> Result<ApiServerConfig, String> {
+        let mut active = self.active.write().map_err(|e| e.to_string())?;
+        active.api.log_level = level;
+        Ok(active.api.clone())
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    // Re-read aegis.toml without applying it, to report whether it still parses and
+    // validates. A file that doesn't exist yet is fine; it is written on the next save.
+    pub fn check_file(&self) -> Result<(), String> {
+        match &self.path {
+            Some(path) if path.exists() => read_config(path).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
+    // Make a validated config current. Restart-required fields keep their running values.
+    fn swap(&self, saved: &mut Config, updated: Config) -> Result<Vec<&'static str>, String> {
+        let mut active = self.active.write().map_err(|e| e.to_string())?;
+        let mut next = updated.clone();
+        next.api = updated.effective_api();
+        next.api.resource_dir = active.api.resource_dir.clone();
+        next.api.log_level = active.api.log_level.clone();
+        next.api.port = active.api.port;
+        next.api.socket_path = active.api.socket_path.clone();
+        next.api.backend_transport = active.api.backend_transport;
+        next.api.wsl_distro = active.api.wsl_distro.clone();
+        next.api.api_base_url = active.api.api_base_url.clone();
+        next.api.ca_cert_path = active.api.ca_cert_path.clone();
+        next.api.request_timeout_ms = active.api.request_timeout_ms;
+        next.api.proxy_url = active.api.proxy_url.clone();
+
+        let pending = restart_required(&saved.effective_api(), &updated.effective_api());
+        *active = next;
+        *saved = updated;
+
+        Ok(pending)
+    }
+
+    pub fn update_preferences(&self, change: impl FnOnce(&mut Preferences)) -> Result<(), String> {
+        self.update(|config| change(&mut config.preferences))
+            .map(|_| ())
+    }
+}
+
+fn restart_required(before: &ApiServerConfig, after: &ApiServerConfig) -> Vec<&'static str> {
+    let changed = [
+        before.port != after.port,
+        before.socket_path != after.socket_path,
+        before.backend_transport != after.backend_transport,
+        before.wsl_distro != after.wsl_distro,
+        before.api_base_url != after.api_base_url,
+        before.ca_cert_path != after.ca_cert_path,
+        before.request_timeout_ms != after.request_timeout_ms,
+        before.proxy_url != after.proxy_url,
+    ];
+    RESTART_REQUIRED_FIELDS
+        .into_iter()
+        .zip(changed)
+        .filter_map(|(field, changed)| changed.then_some(field))
+        .collect()
+}
+
+fn read_config(path: &Path) -> Result<Config, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let contents = Zeroizing::new(config_crypto::decode(&contents)?);
+    let config: Config = toml::from_str(&contents).map_err(|e| e.to_string())?;
+    config.validate()?;
+    Ok(config)
+}
+
+fn write_config(path: &Path, config: &Config) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let mut contents = Zeroizing::new(toml::to_string_pretty(config).map_err(|e| e.to_string())?);
+    if config.encrypt_at_rest {
+        match config_crypto::encode(&contents) {
+            Ok(sealed) => contents = Zeroizing::new(sealed),
+            Err(e) => {
+                tracing::warn!(error = %e, "could not encrypt config, saving it as plaintext")
+            }
+        }
+    }
+    std::fs::write(path, contents.as_bytes()).map_err(|e| e.to_string())
+}
+
+// An encrypted file whose key is gone (keychain reset, another account) would be replaced
+// by the next save; keep a copy so it can be restored once the key is back
+fn preserve_unreadable(path: &Path) {
+    let encrypted =
+        std::fs::read_to_string(path).is_ok_and(|contents| config_crypto::is_encrypted(&contents));
+    if encrypted {
+        let backup = path.with_extension("toml.locked");
+        if let Err(e) = std::fs::copy(path, &backup) {
+            tracing::warn!(path = %backup.display(), error = %e, "could not back up encrypted config");
+        }
+    }
+}
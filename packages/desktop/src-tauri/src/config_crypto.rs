@@ -0,0 +1,133 @@
+// ==============================================================================
+// file_id: SOM-SCR-0102-v1.0.0
+// name: config_crypto.rs
+// description: AEGIS Desktop - Optional encryption of aegis.toml with a key from the OS keychain
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, config, privacy, crypto]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+// Keychain item holding the base64 config key
+const KEYCHAIN_SERVICE: &str = "AEGIS";
+const KEYCHAIN_USER: &str = "config-key";
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+const ENVELOPE_HEADER: &str =
+    "# Settings are encrypted with a key kept in the OS keychain (AEGIS / config-key).\n\
+     # Without that key they can't be read; delete this file to start over with defaults.\n";
+
+// What an encrypted aegis.toml holds: the plaintext flag and the sealed config
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    encrypted: bool,
+    // base64 of nonce followed by ciphertext
+    data: String,
+}
+
+// Whether a config file's contents are an encrypted envelope
+pub fn is_encrypted(contents: &str) -> bool {
+    toml::from_str::<Envelope>(contents).is_ok_and(|envelope| envelope.encrypted)
+}
+
+// The TOML text of a config file, decrypting it first if it is an encrypted envelope
+pub fn decode(contents: &str) -> Result<String, String> {
+    let Ok(envelope) = toml::from_str::<Envelope>(contents) else {
+        return Ok(contents.to_string());
+    };
+    if !envelope.encrypted {
+        return Ok(contents.to_string());
+    }
+
+    let key = load_key()?.ok_or("aegis.toml is encrypted but its key is not in the keychain")?;
+    let sealed = BASE64
+        .decode(envelope.data.trim())
+        .map_err(|e| format!("Encrypted config is corrupt: {}", e))?;
+    if sealed.len() < NONCE_LEN {
+        return Err("Encrypted config is truncated".into());
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+
+    let plaintext = Zeroizing::new(
+        cipher(&key)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "Encrypted config could not be decrypted with the keychain key")?,
+    );
+    String::from_utf8(plaintext.to_vec()).map_err(|e| e.to_string())
+}
+
+// Seal TOML text into an envelope, creating the keychain key on first use.
+// Fails when the keychain can't be reached; the caller falls back to plaintext.
+pub fn encode(plaintext: &str) -> Result<String, String> {
+    let key = match load_key()? {
+        Some(key) => key,
+        None => create_key()?,
+    };
+
+    let nonce = rand::random::<[u8; NONCE_LEN]>();
+    let ciphertext = cipher(&key)
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|e| format!("Could not encrypt config: {}", e))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    let envelope = Envelope {
+        encrypted: true,
+        data: BASE64.encode(sealed),
+    };
+    let body = toml::to_string(&envelope).map_err(|e| e.to_string())?;
+    Ok(format!("{}{}", ENVELOPE_HEADER, body))
+}
+
+fn cipher(key: &[u8]) -> ChaCha20Poly1305 {
+    ChaCha20Poly1305::new(Key::from_slice(key))
+}
+
+fn entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_USER)
+        .map_err(|e| format!("OS keychain is unavailable: {}", e))
+}
+
+// None when the keychain works but has no key yet
+fn load_key() -> Result<Option<Zeroizing<Vec<u8>>>, String> {
+    let encoded = match entry()?.get_password() {
+        Ok(encoded) => Zeroizing::new(encoded),
+        Err(keyring::Error::NoEntry) => return Ok(None),
+        Err(e) => {
+            return Err(format!(
+                "Could not read the config key from the keychain: {}",
+                e
+            ))
+        }
+    };
+    let key = Zeroizing::new(
+        BASE64
+            .decode(encoded.as_bytes())
+            .map_err(|e| format!("Config key in the keychain is corrupt: {}", e))?,
+    );
+    if key.len() != KEY_LEN {
+        return Err("Config key in the keychain has the wrong length".into());
+    }
+    Ok(Some(key))
+}
+
+fn create_key() -> Result<Zeroizing<Vec<u8>>, String> {
+    let key = Zeroizing::new(rand::random::<[u8; KEY_LEN]>().to_vec());
+    let encoded = Zeroizing::new(BASE64.encode(key.as_slice()));
+    entry()?
+        .set_password(&encoded)
+        .map_err(|e| format!("Could not store the config key in the keychain: {}", e))?;
+    tracing::info!("created config encryption key in the keychain");
+    Ok(key)
+}
@@ -0,0 +1,148 @@
+// ==============================================================================
+// file_id: SOM-SCR-0088-v1.0.0
+// name: config_watcher.rs
+// description: AEGIS Desktop - Reloads aegis.toml when it is edited on disk
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, config]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use crate::notifications::notify;
+use notify::{Event, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+// Editors often write a file several times per save; wait for the burst to end
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+// A reload in progress is allowed to finish; past this the watcher is left to itself
+const STOP_TIMEOUT: Duration = Duration::from_secs(2);
+
+enum Message {
+    Changed(notify::Result<Event>),
+    // Drop the watcher, then acknowledge on the sender
+    Stop(mpsc::Sender<()>),
+}
+
+// Handle on the running watcher thread, if there is one
+#[derive(Default)]
+pub struct ConfigWatcherState {
+    sender: Mutex<Option<mpsc::Sender<Message>>>,
+}
+
+// Spawn the background thread that watches the config file for outside edits
+pub fn spawn(app: AppHandle) {
+    let Some(path) = app.state::<ConfigStore>().path().map(Path::to_path_buf) else {
+        return;
+    };
+    let (sender, events) = mpsc::channel::<Message>();
+    if let Ok(mut current) = app.state::<ConfigWatcherState>().sender.lock() {
+        *current = Some(sender.clone());
+    }
+
+    std::thread::spawn(move || {
+        let changes = move |event| {
+            let _ = sender.send(Message::Changed(event));
+        };
+        let mut watcher = match notify::recommended_watcher(changes) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                tracing::warn!(error = %e, "config hot reload unavailable");
+                return;
+            }
+        };
+
+        // Watch the directory, not the file: saving via rename replaces the watched inode
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            tracing::warn!(dir = %dir.display(), error = %e, "could not watch config directory");
+            return;
+        }
+
+        let stopped = 'watch: loop {
+            let event = match events.recv() {
+                Ok(Message::Changed(event)) => event,
+                Ok(Message::Stop(ack)) => break Some(ack),
+                Err(_) => break None,
+            };
+            let touches_config = event.is_ok_and(|event| {
+                !event.kind.is_access()
+                    && event
+                        .paths
+                        .iter()
+                        .any(|changed| changed.file_name() == path.file_name())
+            });
+            if !touches_config {
+                continue;
+            }
+
+            loop {
+                match events.recv_timeout(RELOAD_DEBOUNCE) {
+                    Ok(Message::Changed(_)) => {}
+                    Ok(Message::Stop(ack)) => break 'watch Some(ack),
+                    Err(_) => break,
+                }
+            }
+            reload(&app);
+        };
+
+        drop(watcher);
+        if let Some(ack) = stopped {
+            let _ = ack.send(());
+        }
+    });
+}
+
+// Stop watching and wait for the thread to let go of the watcher, so nothing reloads
+// config while the backend is being torn down. spawn starts it again.
+pub fn stop(app: &AppHandle) {
+    let sender = app
+        .state::<ConfigWatcherState>()
+        .sender
+        .lock()
+        .ok()
+        .and_then(|mut sender| sender.take());
+    let Some(sender) = sender else {
+        return;
+    };
+    let (ack, stopped) = mpsc::channel();
+    // A send error or a dropped ack means the thread had already exited
+    if sender.send(Message::Stop(ack)).is_ok()
+        && matches!(
+            stopped.recv_timeout(STOP_TIMEOUT),
+            Err(mpsc::RecvTimeoutError::Timeout)
+        )
+    {
+        tracing::warn!("config watcher did not stop in time");
+    }
+}
+
+fn reload(app: &AppHandle) {
+    let config = app.state::<ConfigStore>();
+    let before = config.preferences();
+    match config.reload() {
+        Ok(None) => {}
+        Ok(Some(pending)) => {
+            tracing::info!(?pending, "config reloaded");
+            crate::apply_replaced_preferences(app, &before);
+            if !pending.is_empty() {
+                notify(
+                    app,
+                    &format!("Restart AEGIS to apply changes to {}.", pending.join(", ")),
+                );
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "config not reloaded");
+            notify(app, &format!("aegis.toml was not reloaded: {}", e));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// ==============================================================================
+// file_id: SOM-SCR-0099-v1.0.0
+// name: connectivity.rs
+// description: AEGIS Desktop - Networking self-test for troubleshooting the backend
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, network, diagnostics]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ApiServerConfig;
+use crate::health;
+use serde::Serialize;
+use std::future::Future;
+use std::time::Duration;
+use tokio::net::{lookup_host, TcpStream};
+
+// Per-check budget; the checks run concurrently so the whole test stays near this
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Host the update check already talks to, so the internet probe contacts nothing new
+const INTERNET_PROBE: (&str, u16) = ("github.com", 443);
+
+// One row of the troubleshooting checklist
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl CheckResult {
+    fn new(name: &str, result: Result<String, String>) -> Self {
+        let passed = result.is_ok();
+        Self {
+            name: name.into(),
+            passed,
+            detail: result.unwrap_or_else(|e| e),
+        }
+    }
+}
+
+// Resolve localhost, connect to the API port, hit /api/health and, if asked, reach the internet
+pub async fn run(
+    client: &ApiClient,
+    config: &ApiServerConfig,
+    include_internet: bool,
+) -> Vec<CheckResult> {
+    let (resolve, connect, health, internet) = tokio::join!(
+        resolve_localhost(config.port),
+        connect_api(config),
+        check_health(client, config),
+        async {
+            if include_internet {
+                Some(connect_host(INTERNET_PROBE.0, INTERNET_PROBE.1).await)
+            } else {
+                None
+            }
+        },
+    );
+
+    let mut results = vec![
+        CheckResult::new("Resolve localhost", resolve),
+        CheckResult::new("Connect to API port", connect),
+        CheckResult::new("Health endpoint", health),
+    ];
+    if let Some(internet) = internet {
+        results.push(CheckResult::new("Internet access", internet));
+    }
+    results
+}
+
+async fn resolve_localhost(port: u16) -> Result<String, String> {
+    let addresses: Vec<String> = with_timeout(lookup_host(("localhost", port)))
+        .await?
+        .map_err(|e| format!("Could not resolve localhost: {}", e))?
+        .map(|address| address.ip().to_string())
+        .collect();
+    if addresses.is_empty() {
+        return Err("localhost resolved to no addresses".into());
+    }
+    Ok(format!("localhost is {}", addresses.join(", ")))
+}
+
+// The host and port health requests go to, which is localhost:<port> unless api_base_url
+// is set, or api.socket_path when the server listens on a Unix socket
+async fn connect_api(config: &ApiServerConfig) -> Result<String, String> {
+    #[cfg(unix)]
+    if let Some(socket) = config.unix_socket() {
+        with_timeout(tokio::net::UnixStream::connect(socket))
+            .await?
+            .map_err(|e| format!("Could not connect to {}: {}", socket.display(), e))?;
+        return Ok(format!("Connected to {}", socket.display()));
+    }
+
+    let url = config.api_url("/")?;
+    let host = url.host_str().ok_or("API URL has no host")?.to_string();
+    let port = url.port_or_known_default().ok_or("API URL has no port")?;
+    connect_host(&host, port).await
+}
+
+async fn connect_host(host: &str, port: u16) -> Result<String, String> {
+    let stream = with_timeout(TcpStream::connect((host, port)))
+        .await?
+        .map_err(|e| format!("Could not connect to {}:{}: {}", host, port, e))?;
+    let peer = stream
+        .peer_addr()
+        .map(|address| address.to_string())
+        .unwrap_or_else(|_| format!("{}:{}", host, port));
+    Ok(format!("Connected to {}", peer))
+}
+
+async fn check_health(client: &ApiClient, config: &ApiServerConfig) -> Result<String, String> {
+    let report = with_timeout(health::check(client, config)).await?;
+    if report.is_healthy() {
+        return Ok(format!(
+            "Responded in {}ms",
+            report.latency_ms.unwrap_or_default()
+        ));
+    }
+    if report.wrong_service {
+        return Err("Another service answered on the API port".into());
+    }
+    Err(report
+        .error
+        .unwrap_or_else(|| "API server is not healthy".into()))
+}
+
+async fn with_timeout<F: Future>(future: F) -> Result<F::Output, String> {
+    tokio::time::timeout(CHECK_TIMEOUT, future)
+        .await
+        .map_err(|_| format!("Timed out after {}s", CHECK_TIMEOUT.as_secs()))
+}
@@ -0,0 +1,208 @@
+// ==============================================================================
+// file_id: SOM-SCR-0097-v1.0.0
+// name: control.rs
+// description: AEGIS Desktop - Local control socket used by the `aegis` CLI subcommands
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, cli, ipc]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::{generate_token, ApiClient};
+use crate::config::ConfigStore;
+use crate::server::{stop_api_server, ApiServerState, ShutdownOutcome};
+use crate::status;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+// Written to the app config dir so CLI invocations can find the running instance
+pub const ENDPOINT_FILE_NAME: &str = "control.json";
+
+// A client that connects and never sends a line shouldn't block the next one
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ControlCommand {
+    Status,
+    Restart,
+    Stop,
+}
+
+impl ControlCommand {
+    pub fn parse(arg: &str) -> Option<Self> {
+        match arg {
+            "status" => Some(Self::Status),
+            "restart" => Some(Self::Restart),
+            "stop" => Some(Self::Stop),
+            _ => None,
+        }
+    }
+}
+
+// Where the control socket listens; the token proves the caller can read the config dir
+#[derive(Serialize, Deserialize)]
+pub struct Endpoint {
+    pub port: u16,
+    pub token: String,
+}
+
+// One JSON line each way per connection
+#[derive(Serialize, Deserialize)]
+pub struct ControlRequest {
+    pub token: String,
+    pub command: ControlCommand,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ControlReply {
+    pub ok: bool,
+    pub output: String,
+}
+
+pub fn endpoint_path(config_dir: &Path) -> PathBuf {
+    config_dir.join(ENDPOINT_FILE_NAME)
+}
+
+// Listen on a loopback port and publish it with a fresh token. Requests are handled
+// one at a time on a background thread.
+pub fn spawn(app: AppHandle) {
+    let Ok(config_dir) = app.path().app_config_dir() else {
+        tracing::warn!("no config dir, CLI control is unavailable");
+        return;
+    };
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, 0)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::warn!(error = %e, "could not open control socket");
+            return;
+        }
+    };
+    let endpoint = Endpoint {
+        port: listener.local_addr().map(|addr| addr.port()).unwrap_or(0),
+        token: generate_token().to_string(),
+    };
+    if let Err(e) = write_endpoint(&endpoint_path(&config_dir), &endpoint) {
+        tracing::warn!(error = %e, "could not write control endpoint");
+        return;
+    }
+    tracing::debug!(port = endpoint.port, "control socket listening");
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            if let Err(e) = serve(&app, stream, &endpoint.token) {
+                tracing::warn!(error = %e, "control request failed");
+            }
+        }
+    });
+}
+
+// Drop the endpoint file on exit so the CLI reports "not running" straight away
+pub fn remove_endpoint(app: &AppHandle) {
+    if let Ok(config_dir) = app.path().app_config_dir() {
+        let _ = std::fs::remove_file(endpoint_path(&config_dir));
+    }
+}
+
+fn serve(app: &AppHandle, stream: TcpStream, token: &str) -> Result<(), String> {
+    stream
+        .set_read_timeout(Some(REQUEST_READ_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    let mut line = String::new();
+    BufReader::new(&stream)
+        .read_line(&mut line)
+        .map_err(|e| e.to_string())?;
+
+    let reply = match serde_json::from_str::<ControlRequest>(&line) {
+        Ok(request) if request.token == token => {
+            tracing::info!(command = ?request.command, "control command");
+            run(app, request.command)
+        }
+        Ok(_) => ControlReply {
+            ok: false,
+            output: "Rejected: invalid control token".into(),
+        },
+        Err(e) => ControlReply {
+            ok: false,
+            output: format!("Malformed request: {}", e),
+        },
+    };
+
+    let mut body = serde_json::to_string(&reply).map_err(|e| e.to_string())?;
+    body.push('\n');
+    (&stream)
+        .write_all(body.as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+fn run(app: &AppHandle, command: ControlCommand) -> ControlReply {
+    match command {
+        ControlCommand::Status => {
+            let client = app.state::<ApiClient>();
+            let config = app.state::<ConfigStore>().api();
+            let status = tauri::async_runtime::block_on(status::fetch(&client, &config))
+                .map_err(String::from)
+                .and_then(|status| {
+                    serde_json::to_string_pretty(&status).map_err(|e| e.to_string())
+                });
+            match status {
+                Ok(output) => ControlReply { ok: true, output },
+                Err(e) => ControlReply {
+                    ok: false,
+                    output: format!("API server is not responding: {}", e),
+                },
+            }
+        }
+        ControlCommand::Restart => match crate::restart_api_server(app) {
+            Ok(true) => ControlReply {
+                ok: true,
+                output: "API server restarted".into(),
+            },
+            Ok(false) => ControlReply {
+                ok: false,
+                output: "API server restarted but is not healthy yet".into(),
+            },
+            Err(e) => ControlReply {
+                ok: false,
+                output: e,
+            },
+        },
+        ControlCommand::Stop => {
+            let grace = app.state::<ConfigStore>().api().shutdown_grace();
+            let output = match stop_api_server(&app.state::<ApiServerState>(), grace) {
+                ShutdownOutcome::Graceful => "API server stopped",
+                ShutdownOutcome::Forced => "API server did not exit in time and was killed",
+                ShutdownOutcome::NotRunning => "API server was not running",
+            };
+            ControlReply {
+                ok: true,
+                output: output.into(),
+            }
+        }
+    }
+}
+
+// Owner-only on Unix so other local users can't read the token
+fn write_endpoint(path: &Path, endpoint: &Endpoint) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string(endpoint).map_err(|e| e.to_string())?;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| e.to_string())?;
+    file.write_all(contents.as_bytes())
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,205 @@
+// ==============================================================================
+// file_id: SOM-SCR-0094-v1.0.0
+// name: diagnostics.rs
+// description: AEGIS Desktop - Zip bundle of config, logs and status for bug reports
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, diagnostics, support]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::about::{self, AboutInfo};
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::i18n::I18n;
+use crate::logs::ServerLogs;
+use crate::resources;
+use crate::status;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::DialogExt;
+use zeroize::Zeroizing;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+// Most recent server output lines included in the bundle
+const LOG_LINES: usize = 500;
+
+const REDACTED: &str = "[redacted]";
+
+// Any `_`/`-` separated word of a JSON key that marks its value as secret, e.g. GITHUB_TOKEN
+const SENSITIVE_KEY_WORDS: [&str; 9] = [
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "auth",
+    "authorization",
+    "credential",
+    "credentials",
+    "cookie",
+];
+
+#[derive(Debug, Serialize)]
+struct SystemInfo {
+    app: AboutInfo,
+    os: &'static str,
+    os_family: &'static str,
+    arch: &'static str,
+    locale: Option<String>,
+    exported_at: String,
+}
+
+// Ask where to save, then gather and write the bundle. None when the dialog is cancelled.
+pub async fn export(app: &AppHandle) -> Result<Option<PathBuf>, String> {
+    let file_name = format!(
+        "aegis-diagnostics-{}.zip",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let Some(target) = app
+        .dialog()
+        .file()
+        .add_filter("Zip archive", &["zip"])
+        .set_file_name(file_name)
+        .blocking_save_file()
+    else {
+        return Ok(None);
+    };
+    let path = target.into_path().map_err(|e| e.to_string())?;
+
+    let entries = collect(app).await;
+    let redactor = Redactor {
+        home: app
+            .path()
+            .home_dir()
+            .ok()
+            .map(|dir| dir.to_string_lossy().into_owned()),
+        token: app.state::<ApiClient>().token(),
+    };
+
+    let written = path.clone();
+    tauri::async_runtime::spawn_blocking(move || write_bundle(&written, entries, &redactor))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    tracing::info!(path = %path.display(), "diagnostics exported");
+    Ok(Some(path))
+}
+
+enum Entry {
+    Json(Value),
+    Text(String),
+}
+
+async fn collect(app: &AppHandle) -> Vec<(&'static str, Entry)> {
+    let config = app.state::<ConfigStore>();
+    let client = app.state::<ApiClient>();
+
+    let system = SystemInfo {
+        app: about::collect(app).await,
+        os: std::env::consts::OS,
+        os_family: std::env::consts::FAMILY,
+        arch: std::env::consts::ARCH,
+        locale: app.try_state::<I18n>().map(|i18n| i18n.locale()),
+        exported_at: chrono::Local::now().to_rfc3339(),
+    };
+
+    // The raw body keeps fields SystemStatus doesn't model
+    let status = match status::fetch_raw(&client, &config.api()).await {
+        Ok(body) => serde_json::from_str(&body).unwrap_or(Value::String(body)),
+        Err(e) => json!({ "error": e }),
+    };
+    let usage = match resources::server_usage(app.clone()).await {
+        Ok(usage) => json!(usage),
+        Err(e) => json!({ "error": e }),
+    };
+
+    let lines = app.state::<ServerLogs>().snapshot();
+    let logs = lines[lines.len().saturating_sub(LOG_LINES)..]
+        .iter()
+        .map(|line| format!("[{}] {}\n", line.stream.as_str(), line.text))
+        .collect();
+
+    vec![
+        ("system.json", Entry::Json(json!(system))),
+        ("config.json", Entry::Json(json!(config.get()))),
+        ("status.json", Entry::Json(status)),
+        ("resources.json", Entry::Json(usage)),
+        ("server.log", Entry::Text(logs)),
+    ]
+}
+
+fn write_bundle(
+    path: &Path,
+    entries: Vec<(&'static str, Entry)>,
+    redactor: &Redactor,
+) -> Result<(), String> {
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for (name, entry) in entries {
+        let contents = match entry {
+            Entry::Json(mut value) => {
+                redactor.json(&mut value);
+                serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?
+            }
+            Entry::Text(text) => redactor.text(&text),
+        };
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(contents.as_bytes())
+            .map_err(|e| e.to_string())?;
+    }
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// Strips what shouldn't leave the machine: secret-looking keys, the API token,
+// and the home directory (paths under it give away the user name)
+struct Redactor {
+    home: Option<String>,
+    // Sensitive: zeroed when the export finishes
+    token: Zeroizing<String>,
+}
+
+impl Redactor {
+    fn json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if is_sensitive(key) && !value.is_null() {
+                        *value = Value::String(REDACTED.into());
+                    } else {
+                        self.json(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.json(item)),
+            Value::String(text) => *text = self.text(text),
+            _ => {}
+        }
+    }
+
+    fn text(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        if !self.token.is_empty() {
+            text = text.replace(self.token.as_str(), REDACTED);
+        }
+        if let Some(home) = self.home.as_deref().filter(|home| !home.is_empty()) {
+            text = text.replace(home, "~");
+        }
+        text
+    }
+}
+
+fn is_sensitive(key: &str) -> bool {
+    key.to_lowercase()
+        .split(['_', '-', '.'])
+        .any(|word| SENSITIVE_KEY_WORDS.contains(&word))
+}
@@ -0,0 +1,236 @@
+// ==============================================================================
+// file_id: SOM-SCR-0109-v1.0.0
+// name: environment.rs
+// description: AEGIS Desktop - Launch-time sanity checks of everything the backend needs
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, diagnostics, startup]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::backend_install;
+use crate::config::{ApiServerConfig, BackendTransport, ConfigStore};
+use crate::notifications::{self, notify};
+use crate::runtime;
+use crate::server::{ensure_port_available, resolve_dashboard_path, ApiServerState};
+use crate::wsl;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::{AppHandle, Emitter, Manager};
+
+#[cfg(target_os = "windows")]
+use crate::server::CREATE_NO_WINDOW;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+// Sent after the launch check when a critical check failed, with the full report
+pub const SETUP_ISSUE_EVENT: &str = "setup-issue";
+
+// Created and removed again to prove a directory is writable
+const WRITE_PROBE_FILE: &str = ".aegis-write-test";
+
+// One row of the environment report
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvironmentCheck {
+    pub name: &'static str,
+    pub passed: bool,
+    // The backend can't start while this fails
+    pub critical: bool,
+    pub detail: String,
+}
+
+impl EnvironmentCheck {
+    fn new(name: &'static str, critical: bool, result: Result<String, String>) -> Self {
+        let passed = result.is_ok();
+        Self {
+            name,
+            passed,
+            critical,
+            detail: result.unwrap_or_else(|e| e),
+        }
+    }
+}
+
+// Run every check in order. Spawns node and npm for their versions, so call it off the
+// event loop.
+pub fn report(app: &AppHandle) -> Vec<EnvironmentCheck> {
+    let config = app.state::<ConfigStore>();
+    let api = config.api();
+    let (node, npm) = match api.backend_transport {
+        BackendTransport::Wsl => wsl_versions(&api),
+        BackendTransport::Native => native_versions(&api),
+    };
+
+    vec![
+        EnvironmentCheck::new("Node.js", true, node),
+        EnvironmentCheck::new("npm", true, npm),
+        EnvironmentCheck::new("Dashboard", true, check_dashboard(&api)),
+        EnvironmentCheck::new("API port", true, check_port(app, &api)),
+        EnvironmentCheck::new("Config file", true, check_config(&config)),
+        EnvironmentCheck::new(
+            "Log directory",
+            false,
+            check_writable(app.path().app_log_dir().ok()),
+        ),
+        EnvironmentCheck::new(
+            "Config directory",
+            false,
+            check_writable(app.path().app_config_dir().ok()),
+        ),
+        EnvironmentCheck::new("Notifications", false, check_notifications(app)),
+    ]
+}
+
+type Versions = (Result<String, String>, Result<String, String>);
+
+fn native_versions(api: &ApiServerConfig) -> Versions {
+    match runtime::resolve(api.resource_dir.as_deref()) {
+        Ok(runtime) => (
+            version(
+                Command::new(&runtime.node),
+                &runtime.node.display().to_string(),
+            )
+            .map(|v| format!("{} ({})", v, runtime.source.as_str())),
+            version(
+                Command::new(&runtime.npm),
+                &runtime.npm.display().to_string(),
+            ),
+        ),
+        // resolve names the first one missing, so npm is the culprit only when node was found
+        Err(program) => {
+            let missing = Err(format!(
+                "{} was not found on PATH. Node.js is required: {}",
+                program,
+                runtime::NODE_INSTALL_URL
+            ));
+            match runtime::find_executable("node") {
+                Some(node) if program == "npm" => (
+                    version(Command::new(&node), &node.display().to_string()),
+                    missing,
+                ),
+                _ => (missing, Err("Not checked without node".into())),
+            }
+        }
+    }
+}
+
+// node and npm inside the distro the server would run in
+fn wsl_versions(api: &ApiServerConfig) -> Versions {
+    let distro = match wsl::check_distro(api.wsl_distro.as_deref()) {
+        Ok(distro) => distro,
+        Err(e) => return (Err(e), Err("Not checked without WSL".into())),
+    };
+    let in_wsl = |program: &str| {
+        let mut command = wsl::command(Some(&distro));
+        command.args(["-e", program]);
+        version(command, &format!("{} in WSL ({})", program, distro))
+    };
+    (
+        in_wsl("node").map(|v| format!("{} (WSL: {})", v, distro)),
+        in_wsl("npm"),
+    )
+}
+
+// Run the report once at launch off the main thread; a failed critical check is logged,
+// notified and sent as SETUP_ISSUE_EVENT for the diagnostics screen
+pub fn spawn_launch_check(app: AppHandle) {
+    std::thread::spawn(move || {
+        let checks = report(&app);
+        let failed: Vec<&str> = checks
+            .iter()
+            .filter(|check| check.critical && !check.passed)
+            .map(|check| check.name)
+            .collect();
+        if failed.is_empty() {
+            tracing::debug!("environment checks passed");
+            return;
+        }
+        tracing::warn!(?failed, "environment checks failed");
+        notify(
+            &app,
+            &format!("AEGIS setup problem: {} check failed", failed.join(", ")),
+        );
+        let _ = app.emit(SETUP_ISSUE_EVENT, &checks);
+    });
+}
+
+// `<command> --version`, trimmed; `program` names it in errors
+fn version(mut command: Command, program: &str) -> Result<String, String> {
+    command.arg("--version");
+    #[cfg(target_os = "windows")]
+    command.creation_flags(CREATE_NO_WINDOW);
+    let output = command
+        .output()
+        .map_err(|e| format!("{} failed to run: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} --version exited with {}",
+            program, output.status
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn check_dashboard(api: &ApiServerConfig) -> Result<String, String> {
+    let path = resolve_dashboard_path(api).map_err(|e| e.to_string())?;
+    // Possibly a path inside WSL, out of reach from here
+    if api.backend_transport == BackendTransport::Wsl {
+        return Ok(format!("{} (in WSL, not inspected)", path.display()));
+    }
+    let install = backend_install::inspect(&path);
+    install
+        .require(api.server_mode().script())
+        .map_err(|e| e.to_string())?;
+    Ok(path.display().to_string())
+}
+
+// Our own server holding the port counts as free; the lock is held for a whole start
+fn check_port(app: &AppHandle, api: &ApiServerConfig) -> Result<String, String> {
+    match app.state::<ApiServerState>().try_lock_process() {
+        None => return Ok("In use by the AEGIS server while it starts".into()),
+        Some(process) if process.is_some() => return Ok("In use by the AEGIS server".into()),
+        Some(_) => {}
+    }
+    #[cfg(unix)]
+    if let Some(socket) = api.unix_socket() {
+        if std::os::unix::net::UnixStream::connect(socket).is_ok() {
+            return Err(format!(
+                "Socket {} is already in use by another server",
+                socket.display()
+            ));
+        }
+        return Ok(format!("Unix socket {}", socket.display()));
+    }
+    ensure_port_available(api.port)
+        .map(|()| format!("Port {} is free", api.port))
+        .map_err(|e| e.to_string())
+}
+
+fn check_config(config: &ConfigStore) -> Result<String, String> {
+    let path = config.path().ok_or("No config directory; using defaults")?;
+    config
+        .check_file()
+        .map(|()| path.display().to_string())
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn check_writable(dir: Option<PathBuf>) -> Result<String, String> {
+    let dir = dir.ok_or("Could not resolve the directory")?;
+    let probe = dir.join(WRITE_PROBE_FILE);
+    std::fs::create_dir_all(&dir)
+        .and_then(|()| std::fs::write(&probe, b""))
+        .and_then(|()| std::fs::remove_file(&probe))
+        .map(|()| dir.display().to_string())
+        .map_err(|e| format!("{} is not writable: {}", dir.display(), e))
+}
+
+fn check_notifications(app: &AppHandle) -> Result<String, String> {
+    if notifications::supported(app) {
+        Ok("OS notifications".into())
+    } else {
+        Err("No notification service; alerts show as in-window banners".into())
+    }
+}
@@ -0,0 +1,124 @@
+// ==============================================================================
+// file_id: SOM-SCR-0105-v1.0.0
+// name: errors.rs
+// description: AEGIS Desktop - Command errors the dashboard can tell apart by kind
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, errors]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::server::StartError;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+// Serialized as { kind, message } plus port and pid for port_in_use, so the frontend
+// can switch on `kind` instead of matching message text
+#[derive(Debug, Clone)]
+pub enum CommandError {
+    PortInUse { port: u16, pid: Option<u32> },
+    // node or npm is missing, or the server process could not be launched
+    SpawnFailed(String),
+    Timeout(String),
+    // Nothing is listening on the API port
+    NotRunning(String),
+    LockedDown(String),
+    // aegis.toml or the dashboard directory needs fixing before this can work
+    Config(String),
+    // The server answered, but not with something usable
+    Backend(String),
+}
+
+impl CommandError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CommandError::PortInUse { .. } => "port_in_use",
+            CommandError::SpawnFailed(_) => "spawn_failed",
+            CommandError::Timeout(_) => "timeout",
+            CommandError::NotRunning(_) => "not_running",
+            CommandError::LockedDown(_) => "locked_down",
+            CommandError::Config(_) => "config",
+            CommandError::Backend(_) => "backend",
+        }
+    }
+}
+
+impl CommandError {
+    // Append the ID of the request that failed so the user can quote it
+    pub fn with_request_id(self, request_id: &str) -> Self {
+        let tag = |message: String| format!("{} (request ID {})", message, request_id);
+        match self {
+            CommandError::PortInUse { .. } => self,
+            CommandError::SpawnFailed(message) => CommandError::SpawnFailed(tag(message)),
+            CommandError::Timeout(message) => CommandError::Timeout(tag(message)),
+            CommandError::NotRunning(message) => CommandError::NotRunning(tag(message)),
+            CommandError::LockedDown(message) => CommandError::LockedDown(tag(message)),
+            CommandError::Config(message) => CommandError::Config(tag(message)),
+            CommandError::Backend(message) => CommandError::Backend(tag(message)),
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::PortInUse { port, pid } => StartError::PortInUse {
+                port: *port,
+                pid: *pid,
+            }
+            .fmt(f),
+            CommandError::SpawnFailed(message)
+            | CommandError::Timeout(message)
+            | CommandError::NotRunning(message)
+            | CommandError::LockedDown(message)
+            | CommandError::Config(message)
+            | CommandError::Backend(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl Serialize for CommandError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("CommandError", 4)?;
+        error.serialize_field("kind", self.kind())?;
+        error.serialize_field("message", &self.to_string())?;
+        if let CommandError::PortInUse { port, pid } = self {
+            error.serialize_field("port", port)?;
+            error.serialize_field("pid", pid)?;
+        }
+        error.end()
+    }
+}
+
+// Lets code that still returns Result<_, String> use `?` on these
+impl From<CommandError> for String {
+    fn from(error: CommandError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<&StartError> for CommandError {
+    fn from(error: &StartError) -> Self {
+        match error {
+            StartError::PortInUse { port, pid } => CommandError::PortInUse {
+                port: *port,
+                pid: *pid,
+            },
+            StartError::SocketInUse(_)
+            | StartError::WslUnavailable(_)
+            | StartError::InvalidDashboardPath(_)
+            | StartError::MissingScript { .. }
+            | StartError::ProjectRootNotFound { .. }
+            | StartError::DependenciesMissing(_) => CommandError::Config(error.to_string()),
+            StartError::RuntimeNotFound(_) | StartError::SpawnFailed(_) => {
+                CommandError::SpawnFailed(error.to_string())
+            }
+            StartError::NotReady { .. } => CommandError::Timeout(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// ==============================================================================
+// file_id: SOM-SCR-0111-v1.0.0
+// name: exit_hook.rs
+// description: AEGIS Desktop - Optional cleanup program run when the app quits
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, shutdown, config]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_shell::process::CommandEvent;
+use tauri_plugin_shell::ShellExt;
+
+// Longest timeout_ms accepted, so a typo can't stall quitting for hours
+const MAX_TIMEOUT_MS: u64 = 60_000;
+
+// The `[on_exit]` table of aegis.toml. Absent unless the user adds it; nothing runs by default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExitHook {
+    // Absolute path of the executable, started directly rather than through a shell
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    // Killed when still running after this long so it can't block quitting
+    pub timeout_ms: u64,
+}
+
+impl Default for ExitHook {
+    fn default() -> Self {
+        Self {
+            program: PathBuf::new(),
+            args: Vec::new(),
+            timeout_ms: 5000,
+        }
+    }
+}
+
+impl ExitHook {
+    // Part of Config::validate, so a bad hook is reported when aegis.toml is loaded
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.program.is_absolute() {
+            return Err(format!(
+                "on_exit.program must be an absolute path, got {:?}",
+                self.program
+            ));
+        }
+        if !self.program.is_file() {
+            return Err(format!(
+                "on_exit.program {} does not exist",
+                self.program.display()
+            ));
+        }
+        if self.timeout_ms == 0 || self.timeout_ms > MAX_TIMEOUT_MS {
+            return Err(format!(
+                "on_exit.timeout_ms must be between 1 and {}, got {}",
+                MAX_TIMEOUT_MS, self.timeout_ms
+            ));
+        }
+        Ok(())
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+// Run the hook to completion or until its timeout, logging how it ended. Blocks; called
+// from shutdown once the API server has stopped.
+pub fn run(app: &AppHandle, hook: &ExitHook) {
+    let program = hook.program.display().to_string();
+    let spawned = app.shell().command(&hook.program).args(&hook.args).spawn();
+    let (mut events, child) = match spawned {
+        Ok(spawned) => spawned,
+        Err(e) => {
+            tracing::warn!(program, error = %e, "could not run on_exit program");
+            return;
+        }
+    };
+    tracing::info!(program, pid = child.pid(), "running on_exit program");
+
+    let finished = tauri::async_runtime::block_on(async {
+        tokio::time::timeout(hook.timeout(), async {
+            while let Some(event) = events.recv().await {
+                match event {
+                    CommandEvent::Terminated(status) => return Some(status),
+                    CommandEvent::Stderr(line) => {
+                        let line = String::from_utf8_lossy(&line);
+                        tracing::debug!(program, %line, "on_exit program stderr");
+                    }
+                    _ => {}
+                }
+            }
+            None
+        })
+        .await
+    });
+
+    match finished {
+        Ok(Some(status)) if status.code == Some(0) => {
+            tracing::info!(program, "on_exit program finished")
+        }
+        Ok(Some(status)) => tracing::warn!(
+            program,
+            code = ?status.code,
+            signal = ?status.signal,
+            "on_exit program failed"
+        ),
+        Ok(None) => tracing::warn!(program, "on_exit program ended without an exit status"),
+        Err(_) => {
+            tracing::warn!(
+                program,
+                timeout_ms = hook.timeout_ms,
+                "on_exit program timed out; killing it"
+            );
+            let _ = child.kill();
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// ==============================================================================
+// file_id: SOM-SCR-0065-v1.0.0
+// name: health.rs
+// description: AEGIS Desktop - API server health probing
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, health]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::{new_request_id, ApiClient, ApiResponse};
+use crate::config::ApiServerConfig;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+// Value of `service` in the AEGIS /api/health body
+const SERVICE_NAME: &str = "aegis";
+
+#[derive(Deserialize)]
+struct HealthBody {
+    service: Option<String>,
+}
+
+// Outcome of one health request, detailed enough to tell refused, timed out and 5xx apart
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    // The server answered with any HTTP response
+    pub reachable: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+    // Something answered 2xx on the port without identifying as AEGIS
+    // (another app, a proxy or a captive portal)
+    pub wrong_service: bool,
+    // X-Request-Id sent with the check; None when no request was made
+    pub request_id: Option<String>,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        !self.wrong_service
+            && self
+                .status_code
+                .is_some_and(|code| (200..300).contains(&code))
+    }
+
+    fn failed(error: String) -> Self {
+        Self {
+            reachable: false,
+            status_code: None,
+            latency_ms: None,
+            error: Some(error),
+            wrong_service: false,
+            request_id: None,
+        }
+    }
+}
+
+// GET /api/health, timing the round trip. A 2xx only counts if the body names our service.
+pub async fn check(client: &ApiClient, config: &ApiServerConfig) -> HealthReport {
+    let request_id = new_request_id();
+    let started = Instant::now();
+    match client.get_api(config, "/api/health", &request_id).await {
+        Ok(response) => {
+            tracing::trace!(status = %response.status, request_id, "health check");
+            let mut report = classify(&response, config, &request_id);
+            report.latency_ms = Some(started.elapsed().as_millis() as u64);
+            report.request_id = Some(request_id);
+            report
+        }
+        Err(e) => {
+            tracing::trace!(error = %e, request_id, "health check failed");
+            HealthReport {
+                request_id: Some(request_id.clone()),
+                ..HealthReport::failed(format!("{} (request ID {})", e, request_id))
+            }
+        }
+    }
+}
+
+// The report for an answer to the health request, before latency and request ID are added
+fn classify(response: &ApiResponse, config: &ApiServerConfig, request_id: &str) -> HealthReport {
+    let status = response.status;
+    let mut report = HealthReport {
+        reachable: true,
+        status_code: Some(status.as_u16()),
+        latency_ms: None,
+        error: (!status.is_success())
+            .then(|| format!("API server returned {} (request ID {})", status, request_id)),
+        wrong_service: false,
+        request_id: None,
+    };
+
+    if status.is_success() {
+        let service = serde_json::from_str::<HealthBody>(&response.body)
+            .ok()
+            .and_then(|body| body.service);
+        if service.as_deref() != Some(SERVICE_NAME) {
+            tracing::debug!(?service, "health endpoint answered by another service");
+            report.wrong_service = true;
+            report.error = Some(format!(
+                "{} is answered by something other than the AEGIS API",
+                config.endpoint()
+            ));
+        }
+    }
+    report
+}
+
+// Returns true when the API server answers its health endpoint with a 2xx.
+// Timeouts count as unhealthy.
+pub async fn probe(client: &ApiClient, config: &ApiServerConfig) -> bool {
+    check(client, config).await.is_healthy()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    // Answers every request on 127.0.0.1 with 200 and `body`, like another app on the port
+    fn serve_ok(body: &'static str) -> ApiServerConfig {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                    line.clear();
+                }
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        });
+        ApiServerConfig {
+            api_base_url: Some(format!("http://127.0.0.1:{}", port)),
+            ..ApiServerConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn check_flags_a_200_from_another_service() {
+        let config = serve_ok(r#"{"status":"ok","service":"grafana"}"#);
+        let client = ApiClient::build(&config).unwrap();
+
+        let report = check(&client, &config).await;
+        assert!(report.reachable);
+        assert_eq!(report.status_code, Some(200));
+        assert!(report.wrong_service);
+        assert!(!report.is_healthy());
+        assert!(!probe(&client, &config).await);
+    }
+
+    #[tokio::test]
+    async fn check_accepts_the_aegis_server() {
+        let config = serve_ok(r#"{"status":"ok","service":"aegis"}"#);
+        let client = ApiClient::build(&config).unwrap();
+
+        let report = check(&client, &config).await;
+        assert!(!report.wrong_service);
+        assert!(report.is_healthy());
+        assert!(probe(&client, &config).await);
+    }
+
+    fn classify_body(status: StatusCode, body: &str) -> HealthReport {
+        let response = ApiResponse {
+            status,
+            body: body.to_string(),
+        };
+        classify(&response, &ApiServerConfig::default(), "test")
+    }
+
+    #[test]
+    fn aegis_body_is_healthy() {
+        let report = classify_body(StatusCode::OK, r#"{"status":"ok","service":"aegis"}"#);
+        assert!(!report.wrong_service);
+        assert!(report.error.is_none());
+        assert!(report.is_healthy());
+    }
+
+    #[test]
+    fn json_without_service_is_wrong_service() {
+        let report = classify_body(StatusCode::OK, r#"{"status":"ok"}"#);
+        assert!(report.wrong_service);
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn other_service_is_wrong_service() {
+        let report = classify_body(StatusCode::OK, r#"{"service":"grafana"}"#);
+        assert!(report.wrong_service);
+        assert!(report.error.is_some());
+    }
+
+    #[test]
+    fn non_json_body_is_wrong_service() {
+        let report = classify_body(StatusCode::OK, "<html>Sign in to the network</html>");
+        assert!(report.wrong_service);
+    }
+
+    #[test]
+    fn error_status_is_unhealthy_not_wrong_service() {
+        let report = classify_body(StatusCode::SERVICE_UNAVAILABLE, "");
+        assert!(!report.wrong_service);
+        assert!(report.reachable);
+        assert!(!report.is_healthy());
+    }
+}
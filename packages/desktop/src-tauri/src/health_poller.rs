@@ -0,0 +1,288 @@
+// ==============================================================================
+// file_id: SOM-SCR-0073-v1.0.0
+// name: health_poller.rs
+// description: AEGIS Desktop - Background API health polling for the tray indicator
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, health, tray]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::health::{self, HealthReport};
+use crate::{i18n, tray, version_check};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::image::Image;
+use tauri::menu::{IsMenuItem, MenuItem, Submenu};
+use tauri::{AppHandle, Emitter, Manager, Wry};
+use tokio::sync::Notify;
+
+pub const TRAY_ID: &str = "main";
+
+// Emitted to every window when the API goes up or down
+pub const HEALTH_CHANGED_EVENT: &str = "api-health-changed";
+
+pub const HISTORY_MENU_ID: &str = "health_history";
+
+// Checks kept for get_health_history, a little over 8 minutes at the default interval
+const HISTORY_LEN: usize = 100;
+
+// Newest checks listed in the tray's Recent Health submenu
+const TRAY_HISTORY_LEN: usize = 5;
+
+const HEALTHY_ICON: &[u8] = include_bytes!("../icons/tray-healthy.png");
+const DEGRADED_ICON: &[u8] = include_bytes!("../icons/tray-degraded.png");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthState {
+    Up,
+    Down,
+}
+
+impl From<bool> for HealthState {
+    fn from(healthy: bool) -> Self {
+        if healthy {
+            Self::Up
+        } else {
+            Self::Down
+        }
+    }
+}
+
+// Payload of HEALTH_CHANGED_EVENT: the transition plus the report that caused it
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthChange {
+    pub previous: HealthState,
+    pub current: HealthState,
+    pub report: HealthReport,
+}
+
+// One background check, as listed in the health history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthSample {
+    // Unix time in milliseconds
+    pub timestamp_ms: i64,
+    pub reachable: bool,
+    pub healthy: bool,
+    pub latency_ms: Option<u64>,
+}
+
+#[derive(Default)]
+pub struct HealthPollerState {
+    // Lets a changed poll interval take effect without waiting out the current one
+    wake: Notify,
+    // The last HISTORY_LEN checks, oldest first
+    history: Mutex<VecDeque<HealthSample>>,
+}
+
+impl HealthPollerState {
+    pub fn history(&self) -> Vec<HealthSample> {
+        self.history
+            .lock()
+            .map(|history| history.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Replace the history, keeping the newest HISTORY_LEN samples
+    pub fn restore(&self, samples: Vec<HealthSample>) {
+        if let Ok(mut history) = self.history.lock() {
+            let skip = samples.len().saturating_sub(HISTORY_LEN);
+            *history = samples.into_iter().skip(skip).collect();
+        }
+    }
+
+    fn record(&self, report: &HealthReport) {
+        let sample = HealthSample {
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            reachable: report.reachable,
+            healthy: report.is_healthy(),
+            latency_ms: report.latency_ms,
+        };
+        if let Ok(mut history) = self.history.lock() {
+            if history.len() == HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(sample);
+        }
+    }
+}
+
+// Re-read health_poll_interval_secs now; call after the config changes
+pub fn wake(app: &AppHandle) {
+    app.state::<HealthPollerState>().wake.notify_one();
+}
+
+pub fn tray_icon(healthy: bool) -> tauri::Result<Image<'static>> {
+    Image::from_bytes(if healthy { HEALTHY_ICON } else { DEGRADED_ICON })
+}
+
+// Poll the health endpoint and keep the tray icon and tooltip in sync with the result.
+// Transitions after the first poll are also broadcast so the UI doesn't have to poll.
+// The interval is read from the config every iteration; while it is 0 the tray shows
+// "unknown" and the loop sleeps until woken.
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_state: Option<HealthState> = None;
+
+        let poller = app.state::<HealthPollerState>();
+
+        loop {
+            let Some(interval) = app.state::<ConfigStore>().api().health_poll_interval() else {
+                if last_state.take().is_some() {
+                    tracing::info!("health polling disabled");
+                }
+                update_tray_unknown(&app);
+                poller.wake.notified().await;
+                continue;
+            };
+
+            let report = {
+                let client = app.state::<ApiClient>();
+                let config = app.state::<ConfigStore>().api();
+                health::check(&client, &config).await
+            };
+            let current = HealthState::from(report.is_healthy());
+            poller.record(&report);
+            update_tray_history(&app);
+
+            if last_state != Some(current) {
+                tracing::info!(?current, "api health changed");
+                update_tray(&app, current == HealthState::Up);
+
+                if let Some(previous) = last_state {
+                    let change = HealthChange {
+                        previous,
+                        current,
+                        report,
+                    };
+                    if let Err(e) = app.emit(HEALTH_CHANGED_EVENT, change) {
+                        tracing::warn!(error = %e, "could not emit health change");
+                    }
+                }
+                last_state = Some(current);
+
+                // A backend that came up may not be the one this app was built against
+                if current == HealthState::Up {
+                    if let Err(e) = version_check::check(&app).await {
+                        tracing::debug!(error = %e, "version check skipped");
+                    }
+                }
+            }
+
+            let jitter = app.state::<ConfigStore>().api().health_poll_jitter;
+            tokio::select! {
+                _ = tokio::time::sleep(jittered(interval, jitter)) => {}
+                _ = poller.wake.notified() => {}
+            }
+        }
+    });
+}
+
+// `interval` scaled by a fresh random factor in [1 - jitter, 1 + jitter]
+fn jittered(interval: Duration, jitter: f64) -> Duration {
+    if jitter <= 0.0 {
+        return interval;
+    }
+    interval.mul_f64(1.0 + rand::random_range(-jitter..=jitter))
+}
+
+fn update_tray(app: &AppHandle, healthy: bool) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+
+    if let Ok(icon) = tray_icon(healthy) {
+        let _ = tray.set_icon(Some(icon));
+    }
+    let tooltip = if healthy {
+        "tray.tooltip.running"
+    } else {
+        "tray.tooltip.stopped"
+    };
+    let _ = tray.set_tooltip(Some(i18n::t(app, tooltip)));
+}
+
+// Polling is off, so neither icon would be truthful; fall back to the plain app icon
+fn update_tray_unknown(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+
+    let _ = tray.set_icon(app.default_window_icon().cloned());
+    let _ = tray.set_tooltip(Some(i18n::t(app, "tray.tooltip.unknown")));
+}
+
+// Build the tray's Recent Health submenu from the history so far
+pub fn tray_history_submenu(app: &AppHandle) -> tauri::Result<Submenu<Wry>> {
+    let items = history_items(app)?;
+    let entries: Vec<&dyn IsMenuItem<Wry>> = items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<Wry>)
+        .collect();
+    Submenu::with_id_and_items(
+        app,
+        HISTORY_MENU_ID,
+        i18n::t(app, "tray.health_history"),
+        true,
+        &entries,
+    )
+}
+
+// Swap the submenu's entries for the latest checks
+fn update_tray_history(app: &AppHandle) {
+    let Some(submenu) = tray::submenu(app, HISTORY_MENU_ID) else {
+        return;
+    };
+    let Ok(items) = history_items(app) else {
+        return;
+    };
+
+    for item in submenu.items().unwrap_or_default() {
+        let _ = submenu.remove(&item);
+    }
+    for item in &items {
+        let _ = submenu.append(item);
+    }
+}
+
+// Newest first, as disabled rows such as "14:03:12 — Up (12 ms)"
+fn history_items(app: &AppHandle) -> tauri::Result<Vec<MenuItem<Wry>>> {
+    let history = app.state::<HealthPollerState>().history();
+    if history.is_empty() {
+        let empty = MenuItem::new(app, i18n::t(app, "tray.health.empty"), false, None::<&str>)?;
+        return Ok(vec![empty]);
+    }
+
+    history
+        .iter()
+        .rev()
+        .take(TRAY_HISTORY_LEN)
+        .map(|sample| {
+            let time = chrono::DateTime::from_timestamp_millis(sample.timestamp_ms)
+                .map(|at| {
+                    at.with_timezone(&chrono::Local)
+                        .format("%H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let label = if sample.healthy {
+                let latency = sample.latency_ms.unwrap_or_default().to_string();
+                i18n::tf(
+                    app,
+                    "tray.health.up",
+                    &[("time", &time), ("latency", &latency)],
+                )
+            } else {
+                i18n::tf(app, "tray.health.down", &[("time", &time)])
+            };
+            MenuItem::new(app, label, false, None::<&str>)
+        })
+        .collect()
+}
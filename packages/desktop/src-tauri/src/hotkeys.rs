@@ -0,0 +1,118 @@
+// ==============================================================================
+// file_id: SOM-SCR-0076-v1.0.0
+// name: hotkeys.rs
+// description: AEGIS Desktop - Global keyboard shortcuts
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, shortcut]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::lockdown;
+use crate::preferences::Preferences;
+use crate::windows::toggle_main_window;
+use std::sync::Mutex;
+use tauri::{plugin::TauriPlugin, AppHandle, Manager, Wry};
+use tauri_plugin_global_shortcut::{
+    Builder, GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState,
+};
+
+// What a global shortcut does when pressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyAction {
+    ToggleWindow,
+    Lockdown,
+}
+
+// Shortcuts currently registered with the OS
+#[derive(Default)]
+pub struct HotkeyState {
+    toggle: Mutex<Option<Shortcut>>,
+    lockdown: Mutex<Option<Shortcut>>,
+}
+
+impl HotkeyState {
+    fn binding(&self, action: HotkeyAction) -> &Mutex<Option<Shortcut>> {
+        match action {
+            HotkeyAction::ToggleWindow => &self.toggle,
+            HotkeyAction::Lockdown => &self.lockdown,
+        }
+    }
+
+    fn action_for(&self, shortcut: &Shortcut) -> Option<HotkeyAction> {
+        [HotkeyAction::ToggleWindow, HotkeyAction::Lockdown]
+            .into_iter()
+            .find(|action| {
+                self.binding(*action)
+                    .lock()
+                    .map(|bound| bound.as_ref() == Some(shortcut))
+                    .unwrap_or(false)
+            })
+    }
+}
+
+pub fn plugin() -> TauriPlugin<Wry> {
+    Builder::new().with_handler(handle_shortcut).build()
+}
+
+fn handle_shortcut(app: &AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state() != ShortcutState::Pressed {
+        return;
+    }
+
+    match app.state::<HotkeyState>().action_for(shortcut) {
+        Some(HotkeyAction::ToggleWindow) => toggle_main_window(app),
+        Some(HotkeyAction::Lockdown) => {
+            // Stopping the server waits on the process, so keep it off the event loop
+            let app = app.clone();
+            std::thread::spawn(move || lockdown::engage(&app));
+        }
+        None => {}
+    }
+}
+
+// Register the shortcuts saved in preferences. A taken shortcut is logged rather than
+// treated as fatal; the user can rebind it from settings.
+pub fn register_from_preferences(app: &AppHandle, preferences: &Preferences) {
+    for (action, accelerator) in [
+        (HotkeyAction::ToggleWindow, &preferences.toggle_hotkey),
+        (HotkeyAction::Lockdown, &preferences.lockdown_hotkey),
+    ] {
+        if let Err(e) = set_hotkey(app, action, accelerator) {
+            tracing::warn!(?action, error = %e, "global shortcut not registered");
+        }
+    }
+}
+
+// Bind a shortcut to an action, replacing any previous binding for it.
+// The new chord is registered before the old one is released so a failure leaves the old one working.
+pub fn set_hotkey(app: &AppHandle, action: HotkeyAction, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("Invalid shortcut {:?}: {}", accelerator, e))?;
+
+    let state = app.state::<HotkeyState>();
+    let mut bound = state.binding(action).lock().map_err(|e| e.to_string())?;
+    if *bound == Some(shortcut) {
+        return Ok(());
+    }
+
+    let shortcuts = app.global_shortcut();
+    if shortcuts.is_registered(shortcut) {
+        return Err(format!("Shortcut {} is already in use", accelerator));
+    }
+    // The OS refuses chords claimed by other applications
+    shortcuts.register(shortcut).map_err(|e| {
+        format!(
+            "Shortcut {} could not be registered (it may be claimed by another app): {}",
+            accelerator, e
+        )
+    })?;
+
+    if let Some(previous) = bound.replace(shortcut) {
+        let _ = shortcuts.unregister(previous);
+    }
+    Ok(())
+}
@@ -0,0 +1,156 @@
+// ==============================================================================
+// file_id: SOM-SCR-0092-v1.0.0
+// name: i18n.rs
+// description: AEGIS Desktop - Localized strings for the tray menu and native dialogs
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, i18n]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+const FALLBACK_LOCALE: &str = "en";
+
+// Built in so a missing resource dir or key still gets an English label
+const ENGLISH: &str = include_str!("../locales/en.json");
+
+// Locale files live in `<resources>/locales/<language>.json`
+const LOCALES_DIR: &str = "locales";
+
+struct Translations {
+    locale: String,
+    strings: HashMap<String, String>,
+}
+
+// Managed translations for the selected locale, with English behind every lookup
+pub struct I18n {
+    resource_dir: Option<PathBuf>,
+    fallback: HashMap<String, String>,
+    current: RwLock<Translations>,
+}
+
+impl I18n {
+    // Load `locale` (or the OS locale when None), falling back to English if no file matches
+    pub fn new(resource_dir: Option<PathBuf>, locale: Option<&str>) -> Self {
+        let fallback = serde_json::from_str(ENGLISH).unwrap_or_default();
+        let requested = locale.map(str::to_string).unwrap_or_else(system_locale);
+        let current = load(resource_dir.as_deref(), &requested).unwrap_or_else(|| Translations {
+            locale: FALLBACK_LOCALE.into(),
+            strings: HashMap::new(),
+        });
+        tracing::info!(requested = %requested, locale = %current.locale, "locale loaded");
+
+        Self {
+            resource_dir,
+            fallback,
+            current: RwLock::new(current),
+        }
+    }
+
+    // Switch locales; None goes back to the OS locale. Unknown locales are rejected.
+    pub fn set_locale(&self, locale: Option<&str>) -> Result<String, String> {
+        let requested = locale.map(str::to_string).unwrap_or_else(system_locale);
+        let translations = match load(self.resource_dir.as_deref(), &requested) {
+            Some(translations) => translations,
+            // The OS may use a language we have no file for; that's not an error
+            None if locale.is_none() => Translations {
+                locale: FALLBACK_LOCALE.into(),
+                strings: HashMap::new(),
+            },
+            None => return Err(format!("No translations for locale {:?}", requested)),
+        };
+
+        let loaded = translations.locale.clone();
+        *self.current.write().map_err(|e| e.to_string())? = translations;
+        Ok(loaded)
+    }
+
+    pub fn locale(&self) -> String {
+        self.current
+            .read()
+            .map(|current| current.locale.clone())
+            .unwrap_or_else(|_| FALLBACK_LOCALE.into())
+    }
+
+    // Look up a key in the current locale, then English, then return the key itself
+    pub fn get(&self, key: &str) -> String {
+        self.current
+            .read()
+            .ok()
+            .and_then(|current| current.strings.get(key).cloned())
+            .or_else(|| self.fallback.get(key).cloned())
+            .unwrap_or_else(|| {
+                tracing::debug!(key, "missing translation");
+                key.to_string()
+            })
+    }
+
+    // Look up a key and fill in `{name}` placeholders
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.get(key), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+}
+
+// Translate through the managed I18n, or fall back to the key before it is set up
+pub fn t(app: &AppHandle, key: &str) -> String {
+    tf(app, key, &[])
+}
+
+pub fn tf(app: &AppHandle, key: &str, args: &[(&str, &str)]) -> String {
+    match app.try_state::<I18n>() {
+        Some(i18n) => i18n.format(key, args),
+        None => key.to_string(),
+    }
+}
+
+// OS locale as a lowercase tag such as `de-de`; English if it can't be read
+fn system_locale() -> String {
+    sys_locale::get_locale().unwrap_or_else(|| FALLBACK_LOCALE.into())
+}
+
+// Try the full tag (`pt-br.json`), then the language (`pt.json`)
+fn load(resource_dir: Option<&Path>, locale: &str) -> Option<Translations> {
+    let tag = locale
+        .split('.')
+        .next()
+        .unwrap_or(locale)
+        .replace('_', "-")
+        .to_lowercase();
+    let language = tag.split('-').next().unwrap_or(&tag).to_string();
+
+    for candidate in [tag.clone(), language] {
+        if candidate == FALLBACK_LOCALE {
+            return Some(Translations {
+                locale: candidate,
+                strings: HashMap::new(),
+            });
+        }
+        let Some(dir) = resource_dir else {
+            continue;
+        };
+        let path = dir.join(LOCALES_DIR).join(format!("{}.json", candidate));
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        match serde_json::from_str(&contents) {
+            Ok(strings) => {
+                return Some(Translations {
+                    locale: candidate,
+                    strings,
+                })
+            }
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "invalid locale file")
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,78 @@
+// ==============================================================================
+// file_id: SOM-SCR-0077-v1.0.0
+// name: lockdown.rs
+// description: AEGIS Desktop - Panic/lockdown mode that hides the app and stops the backend
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, privacy]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::errors::CommandError;
+use crate::server::{stop_api_server, ApiServerState};
+use crate::windows;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+// Set while locked down; the API server may not be started again until it is cleared
+#[derive(Default)]
+pub struct LockdownState {
+    active: AtomicBool,
+}
+
+impl LockdownState {
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    // Error for commands that would bring the backend back up
+    pub fn ensure_unlocked(&self) -> Result<(), CommandError> {
+        if self.is_active() {
+            Err(CommandError::LockedDown(
+                "AEGIS is locked down; unlock it before starting the API server".into(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Hide every window and kill the API server without waiting for a graceful exit
+pub fn engage(app: &AppHandle) {
+    app.state::<LockdownState>()
+        .active
+        .store(true, Ordering::SeqCst);
+
+    for window in app.webview_windows().values() {
+        let _ = window.hide();
+    }
+    // Their pages were given the token that is about to be wiped
+    windows::close_route_windows(app);
+    windows::sync_visibility(app);
+
+    let outcome = stop_api_server(&app.state::<ApiServerState>(), Duration::ZERO);
+    app.state::<ApiClient>().wipe_token();
+    tracing::warn!(?outcome, "lockdown engaged");
+
+    let clear_clipboard = app
+        .try_state::<ConfigStore>()
+        .is_some_and(|config| config.preferences().lockdown_clears_clipboard);
+    if clear_clipboard {
+        let _ = app.clipboard().clear();
+    }
+}
+
+// Allow the API server to be started again with a fresh token; it is not restarted automatically
+pub fn release(app: &AppHandle) {
+    app.state::<ApiClient>().rotate_token();
+    app.state::<LockdownState>()
+        .active
+        .store(false, Ordering::SeqCst);
+    tracing::info!("lockdown released");
+}
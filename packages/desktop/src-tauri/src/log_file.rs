@@ -0,0 +1,130 @@
+// ==============================================================================
+// file_id: SOM-SCR-0078-v1.0.0
+// name: log_file.rs
+// description: AEGIS Desktop - Size-rotated log file written on a background thread
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, logging]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+const LOG_FILE_NAME: &str = "aegis.log";
+
+// aegis.log plus aegis.1.log .. aegis.4.log
+const MAX_FILES: usize = 5;
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+enum Message {
+    Line(String),
+    // Acknowledged once every line queued before it is on disk
+    Flush(Sender<()>),
+}
+
+// Handle to the log writer; sending never blocks the caller
+#[derive(Clone)]
+pub struct LogFile {
+    path: PathBuf,
+    sender: Sender<Message>,
+}
+
+impl LogFile {
+    pub fn open(log_dir: &Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(log_dir)?;
+        let path = log_dir.join(LOG_FILE_NAME);
+        let file = open_append(&path)?;
+
+        let (sender, receiver) = mpsc::channel();
+        let writer_path = path.clone();
+        std::thread::spawn(move || write_loop(&writer_path, file, receiver));
+
+        Ok(Self { path, sender })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or(&self.path)
+    }
+
+    // Queue one line tagged with where it came from, e.g. `stdout` or `app`
+    pub fn write(&self, source: &str, text: &str) {
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
+        self.write_raw(format!("{} [{}] {}\n", timestamp, source, text));
+    }
+
+    // Queue an already formatted line, e.g. from the tracing subscriber
+    pub fn write_raw(&self, line: String) {
+        let _ = self.sender.send(Message::Line(line));
+    }
+
+    // Wait for the queued lines to be written, e.g. before exiting. Gives up after
+    // `timeout` so a stuck disk can't hold up quitting.
+    pub fn flush(&self, timeout: Duration) {
+        let (ack, flushed) = mpsc::channel();
+        if self.sender.send(Message::Flush(ack)).is_ok() {
+            let _ = flushed.recv_timeout(timeout);
+        }
+    }
+}
+
+fn open_append(path: &Path) -> std::io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+// Runs until every LogFile handle is dropped
+fn write_loop(path: &Path, mut file: File, receiver: Receiver<Message>) {
+    let mut size = file.metadata().map(|meta| meta.len()).unwrap_or(0);
+
+    for message in receiver {
+        let line = match message {
+            Message::Line(line) => line,
+            Message::Flush(ack) => {
+                let _ = file.flush();
+                let _ = ack.send(());
+                continue;
+            }
+        };
+        if size > 0 && size + line.len() as u64 > MAX_FILE_BYTES {
+            rotate(path);
+            match open_append(path) {
+                Ok(fresh) => {
+                    file = fresh;
+                    size = 0;
+                }
+                Err(_) => continue,
+            }
+        }
+        if file.write_all(line.as_bytes()).is_ok() {
+            size += line.len() as u64;
+        }
+    }
+}
+
+// Shift aegis.N.log up by one, dropping the oldest
+fn rotate(path: &Path) {
+    for index in (1..MAX_FILES).rev() {
+        let from = numbered(path, index - 1);
+        let _ = std::fs::rename(from, numbered(path, index));
+    }
+}
+
+fn numbered(path: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        return path.to_path_buf();
+    }
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("aegis");
+    path.with_file_name(format!("{}.{}.log", stem, index))
+}
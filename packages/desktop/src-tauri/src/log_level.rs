@@ -0,0 +1,137 @@
+// ==============================================================================
+// file_id: SOM-SCR-0110-v1.0.0
+// name: log_level.rs
+// description: AEGIS Desktop - Changing the backend's log level while it runs
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, logging]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::{new_request_id, ApiClient};
+use crate::config::{ApiServerConfig, ConfigStore, LOG_LEVEL_ENV};
+use crate::errors::CommandError;
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::json;
+use tauri::{AppHandle, Emitter, Manager};
+
+// Levels the backend's logger understands, quietest first
+pub const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+// Sent with a BackendLogLevel whenever the level changes, for the log viewer
+pub const LOG_LEVEL_EVENT: &str = "backend-log-level-changed";
+
+// Backend endpoint that switches the level without a restart
+const LOG_LEVEL_PATH: &str = "/api/log-level";
+
+// How a new level reached the backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Applied {
+    // The running server switched over
+    Live,
+    // The server has no live endpoint, so it was restarted with LOG_LEVEL
+    Restarted,
+    // No server is running; the next one starts with it
+    NextStart,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendLogLevel {
+    // None when neither set_backend_log_level nor server_env chose one
+    pub level: Option<String>,
+    pub applied: Option<Applied>,
+}
+
+// The level servers are spawned with: this run's choice, then server_env's LOG_LEVEL
+pub fn current(config: &ApiServerConfig) -> Option<String> {
+    config
+        .log_level
+        .clone()
+        .or_else(|| config.server_env.get(LOG_LEVEL_ENV).cloned())
+}
+
+// Switch the backend to `level` (one of LOG_LEVELS) for the rest of this run. Tries the
+// live endpoint first and restarts the server with LOG_LEVEL when it is missing. On
+// failure the previous level stays in effect.
+pub async fn set(app: &AppHandle, level: &str) -> Result<BackendLogLevel, CommandError> {
+    let level = level.trim().to_ascii_lowercase();
+    if !LOG_LEVELS.contains(&level.as_str()) {
+        return Err(CommandError::Config(format!(
+            "Unknown log level {:?}; use one of {}",
+            level,
+            LOG_LEVELS.join(", ")
+        )));
+    }
+
+    let store = app.state::<ConfigStore>();
+    let previous = store.api().log_level;
+    let config = store
+        .set_log_level(Some(level.clone()))
+        .map_err(CommandError::Config)?;
+    let applied = match apply(app, &config, &level).await {
+        Ok(applied) => applied,
+        Err(e) => {
+            let _ = store.set_log_level(previous);
+            return Err(e);
+        }
+    };
+
+    tracing::info!(level, ?applied, "backend log level changed");
+    let change = BackendLogLevel {
+        level: Some(level),
+        applied: Some(applied),
+    };
+    let _ = app.emit(LOG_LEVEL_EVENT, &change);
+    Ok(change)
+}
+
+async fn apply(
+    app: &AppHandle,
+    config: &ApiServerConfig,
+    level: &str,
+) -> Result<Applied, CommandError> {
+    let request_id = new_request_id();
+    let result = app
+        .state::<ApiClient>()
+        .post_api(
+            config,
+            LOG_LEVEL_PATH,
+            &json!({ "level": level }),
+            &request_id,
+        )
+        .await;
+
+    match result {
+        Ok(response) if response.status.is_success() => Ok(Applied::Live),
+        Ok(response)
+            if matches!(
+                response.status,
+                StatusCode::NOT_FOUND
+                    | StatusCode::METHOD_NOT_ALLOWED
+                    | StatusCode::NOT_IMPLEMENTED
+            ) =>
+        {
+            tracing::info!(
+                level,
+                "backend has no live log level endpoint; restarting it"
+            );
+            let handle = app.clone();
+            tauri::async_runtime::spawn_blocking(move || crate::restart_api_server(&handle))
+                .await
+                .map_err(|e| CommandError::Backend(e.to_string()))?
+                .map_err(CommandError::SpawnFailed)?;
+            Ok(Applied::Restarted)
+        }
+        Ok(response) => Err(CommandError::Backend(format!(
+            "API server returned {} for the log level change",
+            response.status
+        ))
+        .with_request_id(&request_id)),
+        Err(CommandError::NotRunning(_)) => Ok(Applied::NextStart),
+        Err(e) => Err(e.with_request_id(&request_id)),
+    }
+}
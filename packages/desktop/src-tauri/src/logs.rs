@@ -0,0 +1,246 @@
+// ==============================================================================
+// file_id: SOM-SCR-0068-v1.0.0
+// name: logs.rs
+// description: AEGIS Desktop - Captured API server output
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, logging]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::log_file::LogFile;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, SyncSender};
+use std::sync::{Arc, Mutex, OnceLock};
+use tauri::{AppHandle, Emitter};
+
+const MAX_LINES: usize = 1000;
+
+// Pushed for every captured line once the frontend stream is attached
+pub const LOG_LINE_EVENT: &str = "server-log-line";
+
+// Lines queued for the frontend; past this the reader threads drop instead of waiting
+const LIVE_QUEUE_LINES: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+impl LogStream {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogStream::Stdout => "stdout",
+            LogStream::Stderr => "stderr",
+        }
+    }
+}
+
+// Rough severity for colouring a log viewer: the stream, adjusted by obvious keywords
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn guess(stream: LogStream, text: &str) -> Self {
+        let lower = text.to_ascii_lowercase();
+        if lower.contains("warn") {
+            LogLevel::Warn
+        } else if stream == LogStream::Stderr || lower.contains("error") {
+            LogLevel::Error
+        } else {
+            LogLevel::Info
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub stream: LogStream,
+    pub text: String,
+}
+
+// Narrows get_server_logs down before anything is sent to the webview
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LogFilter {
+    // Substring to look for, or a regular expression when `regex` is set
+    pub query: Option<String>,
+    pub regex: bool,
+    pub stream: Option<LogStream>,
+    // Keep only the newest this many matches
+    pub limit: Option<usize>,
+}
+
+enum Matcher<'a> {
+    All,
+    Substring(&'a str),
+    Pattern(Regex),
+}
+
+impl Matcher<'_> {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Matcher::All => true,
+            Matcher::Substring(query) => text.contains(query),
+            Matcher::Pattern(pattern) => pattern.is_match(text),
+        }
+    }
+}
+
+impl LogFilter {
+    fn matcher(&self) -> Result<Matcher<'_>, String> {
+        match self.query.as_deref() {
+            None | Some("") => Ok(Matcher::All),
+            Some(query) if !self.regex => Ok(Matcher::Substring(query)),
+            Some(query) => Regex::new(query)
+                .map(Matcher::Pattern)
+                .map_err(|e| format!("Invalid log filter pattern: {}", e)),
+        }
+    }
+}
+
+// Payload of `server-log-line`. `dropped` counts lines skipped just before this one
+// because the frontend fell behind; they are still in the buffer and the log file.
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveLogLine {
+    pub stream: LogStream,
+    pub level: LogLevel,
+    pub text: String,
+    pub dropped: usize,
+}
+
+// Bounded ring buffer of the most recent server output lines,
+// mirrored to the on-disk log and streamed to the frontend once attached
+#[derive(Clone, Default)]
+pub struct ServerLogs {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+    file: Arc<OnceLock<LogFile>>,
+    live: Arc<OnceLock<SyncSender<LogLine>>>,
+    dropped: Arc<AtomicUsize>,
+}
+
+impl ServerLogs {
+    pub fn attach_file(&self, file: LogFile) {
+        let _ = self.file.set(file);
+    }
+
+    // Emit each new line as `server-log-line`. Emitting happens on a forwarding thread
+    // behind a bounded queue so a slow webview never stalls the pipe readers.
+    pub fn attach_frontend(&self, app: AppHandle) {
+        let (sender, receiver) = mpsc::sync_channel::<LogLine>(LIVE_QUEUE_LINES);
+        if self.live.set(sender).is_err() {
+            return;
+        }
+        let dropped = self.dropped.clone();
+        std::thread::spawn(move || {
+            for line in receiver {
+                let payload = LiveLogLine {
+                    level: LogLevel::guess(line.stream, &line.text),
+                    stream: line.stream,
+                    text: line.text,
+                    dropped: dropped.swap(0, Ordering::Relaxed),
+                };
+                let _ = app.emit(LOG_LINE_EVENT, payload);
+            }
+        });
+    }
+
+    pub fn file(&self) -> Option<&LogFile> {
+        self.file.get()
+    }
+
+    pub fn push(&self, stream: LogStream, text: String) {
+        if let Some(file) = self.file.get() {
+            file.write(stream.as_str(), &text);
+        }
+        if let Some(live) = self.live.get() {
+            let line = LogLine {
+                stream,
+                text: text.clone(),
+            };
+            if live.try_send(line).is_err() {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        if let Ok(mut lines) = self.lines.lock() {
+            if lines.len() == MAX_LINES {
+                lines.pop_front();
+            }
+            lines.push_back(LogLine { stream, text });
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<LogLine> {
+        self.lines
+            .lock()
+            .map(|lines| lines.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Matching lines, oldest first. Fails only on an invalid regex.
+    pub fn filtered(&self, filter: &LogFilter) -> Result<Vec<LogLine>, String> {
+        let matcher = filter.matcher()?;
+        let Ok(lines) = self.lines.lock() else {
+            return Ok(Vec::new());
+        };
+        let mut filtered: Vec<LogLine> = lines
+            .iter()
+            .rev()
+            .filter(|line| filter.stream.is_none_or(|stream| line.stream == stream))
+            .filter(|line| matcher.matches(&line.text))
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        filtered.reverse();
+        Ok(filtered)
+    }
+
+    // Drop the buffered lines; the log file keeps its history
+    pub fn clear(&self) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.clear();
+        }
+    }
+
+    // Last `count` lines from one stream, oldest first
+    pub fn recent(&self, stream: LogStream, count: usize) -> Vec<String> {
+        let Ok(lines) = self.lines.lock() else {
+            return Vec::new();
+        };
+        let mut recent: Vec<String> = lines
+            .iter()
+            .rev()
+            .filter(|line| line.stream == stream)
+            .take(count)
+            .map(|line| line.text.clone())
+            .collect();
+        recent.reverse();
+        recent
+    }
+
+    // Drain a child pipe on its own thread until the process closes it
+    pub fn capture<R: Read + Send + 'static>(&self, stream: LogStream, pipe: R) {
+        let logs = self.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(pipe).lines() {
+                match line {
+                    Ok(text) => logs.push(stream, text),
+                    Err(_) => break,
+                }
+            }
+        });
+    }
+}
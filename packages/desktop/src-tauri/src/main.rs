@@ -6,185 +6,1594 @@
 // category: desktop
 // tags: [tauri, rust, desktop]
 // created: 2025-12-09
-// modified: 2025-12-09
+// modified: 2026-10-14
 // version: 1.0.0
 // ==============================================================================
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::process::{Child, Command};
-use std::sync::Mutex;
+mod about;
+mod autostart;
+mod backend_install;
+mod breaker;
+mod cli;
+mod client;
+mod clipboard;
+mod config;
+mod config_crypto;
+mod config_watcher;
+mod connectivity;
+mod control;
+mod diagnostics;
+mod environment;
+mod errors;
+mod exit_hook;
+mod health;
+mod health_poller;
+mod hotkeys;
+mod i18n;
+mod lockdown;
+mod log_file;
+mod log_level;
+mod logs;
+mod notifications;
+mod onboarding;
+mod preferences;
+mod profiles;
+mod recovery;
+mod resources;
+mod runtime;
+mod safe_mode;
+mod server;
+mod signals;
+mod snapshot;
+mod stale_server;
+mod startup;
+mod status;
+mod telemetry;
+mod theme;
+mod tray;
+#[cfg(unix)]
+mod unix_socket;
+mod updates;
+mod version_check;
+mod watchdog;
+mod window_state;
+mod windows;
+mod wsl;
+
+use about::AboutInfo;
+use backend_install::InstallReport;
+use breaker::{BreakerStatus, CircuitBreaker};
+use client::{ApiClient, ProxySettings};
+use clipboard::ClipboardState;
+use config::{Config, ConfigStore, ServerMode};
+use config_watcher::ConfigWatcherState;
+use connectivity::CheckResult;
+use environment::EnvironmentCheck;
+use errors::CommandError;
+use health::HealthReport;
+use health_poller::{HealthPollerState, HealthSample};
+use hotkeys::{HotkeyAction, HotkeyState};
+use i18n::I18n;
+use lockdown::LockdownState;
+use log_file::LogFile;
+use log_level::BackendLogLevel;
+use logs::{LogFilter, LogLine, ServerLogs};
+use notifications::{notify, NotificationManager};
+use preferences::Preferences;
+use resources::{ResourceMonitor, ResourceUsage};
+use runtime::NodeSource;
+use safe_mode::{SafeModeState, SafeModeStatus};
+use server::{
+    start_api_server, stop_api_server, ApiServerState, BackendReload, DashboardLocation,
+    StartApiStatus, StartupMetrics, StateHealth, RELOAD_EVENT,
+};
+use snapshot::AppSnapshot;
+use stale_server::{FreePortOutcome, OrphanedBackend, PortTest};
+use status::SystemStatus;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
 use tauri::{
-    menu::{Menu, MenuItem},
-    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    Manager, State, WindowEvent,
+    menu::{CheckMenuItem, Menu, MenuItem},
+    tray::TrayIconBuilder,
+    AppHandle, Emitter, Manager, RunEvent, State, WindowEvent, Wry,
+};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use tauri_plugin_shell::ShellExt;
+use theme::ThemePreference;
+use tray::{TrayClickState, TrayMenu};
+use updates::UpdateCheck;
+use version_check::{VersionCompatibility, VersionState};
+use watchdog::{PauseStatus, WatchdogState};
+use window_state::WindowStateStore;
+use windows::{
+    navigate_main_window, open_settings_window, show_main_window, AutoHideState,
+    MainWindowVisibility, RouteWindows,
 };
 
-// State to hold the API server process
-struct ApiServerState {
-    process: Mutex<Option<Child>>,
+// How long quitting waits for queued log lines to reach the log file
+const LOG_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+
+// Menu accelerator using the platform's primary modifier: Cmd on macOS, Ctrl elsewhere
+fn accelerator(key: &str) -> String {
+    let modifier = if cfg!(target_os = "macos") {
+        "Cmd"
+    } else {
+        "Ctrl"
+    };
+    format!("{}+{}", modifier, key)
 }
 
-// Start the Express API server
-fn start_api_server() -> Option<Child> {
-    let project_root = std::env::current_dir()
-        .ok()?
-        .parent()?
-        .parent()?
-        .to_path_buf();
+// Build the tray menu with labels in the current locale
+fn build_tray_menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+    let item = |id: &str, key: &str, shortcut: Option<&str>| {
+        MenuItem::with_id(app, id, i18n::t(app, key), true, shortcut.map(accelerator))
+    };
 
-    let dashboard_path = project_root.join("packages").join("dashboard");
+    let show = item("show", "tray.show", Some("D"))?;
+    let browser = item("browser", "tray.browser", None)?;
+    let status = item("status", "tray.status", Some("I"))?;
+    let copy_status = item("copy_status", "tray.copy_status", None)?;
+    let restart = item("restart", "tray.restart", Some("R"))?;
+    let settings = item("settings", "tray.settings", Some(","))?;
+    let launch_at_login = CheckMenuItem::with_id(
+        app,
+        autostart::TRAY_ITEM_ID,
+        i18n::t(app, "tray.launch_at_login"),
+        true,
+        autostart::is_enabled(app).unwrap_or(false),
+        None::<&str>,
+    )?;
+    let always_on_top = CheckMenuItem::with_id(
+        app,
+        windows::ALWAYS_ON_TOP_ITEM_ID,
+        i18n::t(app, "tray.always_on_top"),
+        true,
+        windows::always_on_top(app),
+        None::<&str>,
+    )?;
+    let logs = item("logs", "tray.logs", Some("L"))?;
+    let health_menu = health_poller::tray_history_submenu(app)?;
+    let theme_menu = theme::tray_submenu(app)?;
+    let profile_menu = profiles::tray_submenu(app)?;
+    let updates = item("updates", "tray.updates", None)?;
+    let about = item("about", "tray.about", None)?;
+    let quit = item("quit", "tray.quit", Some("Q"))?;
 
-    #[cfg(target_os = "windows")]
-    {
-        Command::new("cmd")
-            .args(["/C", "npm", "run", "dev:server"])
-            .current_dir(&dashboard_path)
-            .spawn()
-            .ok()
+    let menu = Menu::with_items(
+        app,
+        &[
+            &show,
+            &browser,
+            &status,
+            &copy_status,
+            &health_menu,
+            &restart,
+            &settings,
+            &launch_at_login,
+            &always_on_top,
+            &logs,
+            &theme_menu,
+        ],
+    )?;
+    // Only shown once aegis.toml defines a profile
+    if let Some(profile_menu) = &profile_menu {
+        menu.append(profile_menu)?;
     }
+    menu.append_items(&[&updates, &about, &quit])?;
+    Ok(menu)
+}
 
-    #[cfg(not(target_os = "windows"))]
-    {
-        Command::new("npm")
-            .args(["run", "dev:server"])
-            .current_dir(&dashboard_path)
-            .spawn()
-            .ok()
+// The configured CA or timeouts couldn't be applied; tell the user what to fix in aegis.toml
+fn report_client_error(app: &AppHandle, error: &str) {
+    notify(app, "AEGIS could not apply its connection settings");
+    app.dialog()
+        .message(format!(
+            "{}\n\nAEGIS is using default connection settings until this is fixed. Check \
+             api.ca_cert_path in aegis.toml.",
+            error
+        ))
+        .title("AEGIS connection settings")
+        .kind(MessageDialogKind::Warning)
+        .show(|_| {});
+}
+
+// Rebuild the tray menu after its labels or the profile list changed
+fn refresh_tray_menu(app: &AppHandle) {
+    let result = build_tray_menu(app).and_then(|menu| tray::set_menu(app, menu));
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "could not rebuild tray menu");
+    }
+}
+
+// The one exit path (tray Quit, closing the window without close_to_tray, signals), in
+// order: retire the watchdog and config watcher so neither restarts anything, close the
+// route windows, stop the API server within its grace period, run the on_exit program if
+// one is configured, release the instance lock, flush the log file and exit. Blocks for
+// the grace period and the hook's timeout; call it off the event loop.
+fn shutdown(app: &AppHandle) {
+    tracing::info!("quitting");
+    watchdog::stop(app);
+    config_watcher::stop(app);
+    windows::close_route_windows(app);
+
+    let grace = app.state::<ConfigStore>().api().shutdown_grace();
+    let outcome = stop_api_server(&app.state::<ApiServerState>(), grace);
+    tracing::info!(?outcome, "api server stopped for shutdown");
+    // app.exit doesn't run destructors, so secrets are overwritten here instead of on drop
+    app.state::<ApiClient>().wipe_token();
+
+    if let Some(hook) = app.state::<ConfigStore>().get().on_exit {
+        exit_hook::run(app, &hook);
+    }
+
+    // Release the instance lock before exiting so a relaunch right after isn't refused
+    tauri_plugin_single_instance::destroy(app);
+    if let Some(file) = app.state::<ServerLogs>().file() {
+        file.flush(LOG_FLUSH_TIMEOUT);
+    }
+    app.exit(0);
+}
+
+// Quit, first asking for confirmation if the backend is in the middle of something.
+// An unreachable or slow backend (request timeout) counts as idle so quitting never hangs.
+fn request_quit(app: &AppHandle) {
+    let confirm = app
+        .try_state::<ConfigStore>()
+        .is_none_or(|config| config.preferences().quit_confirm_enabled);
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let busy = confirm && {
+            let client = app.state::<ApiClient>();
+            let config = app.state::<ConfigStore>().api();
+            status::fetch_busy(&client, &config).await.unwrap_or(false)
+        };
+
+        if !busy {
+            std::thread::spawn(move || shutdown(&app));
+            return;
+        }
+
+        let handle = app.clone();
+        app.dialog()
+            .message(i18n::t(&app, "dialog.quit.message"))
+            .title(i18n::t(&app, "dialog.quit.title"))
+            .kind(MessageDialogKind::Warning)
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                i18n::t(&app, "dialog.quit.confirm"),
+                i18n::t(&app, "dialog.cancel"),
+            ))
+            .show(move |quit| {
+                if quit {
+                    std::thread::spawn(move || shutdown(&handle));
+                }
+            });
+    });
+}
+
+// Stop the API server, wait for it to exit, then start a fresh one and report its health.
+// backend-reloading events bracket the restart so the dashboard can overlay the gap.
+fn restart_api_server(app: &AppHandle) -> Result<bool, String> {
+    app.state::<LockdownState>().ensure_unlocked()?;
+    safe_mode::exit(app);
+
+    let _ = app.emit(RELOAD_EVENT, BackendReload::started());
+    let result = restart_in_place(app);
+    let _ = app.emit(RELOAD_EVENT, BackendReload::finished(&result));
+    if let Ok(false) = result {
+        notify(app, "AEGIS backend restarted but has not become healthy");
     }
+    result
 }
 
-// Stop the API server process
-fn stop_api_server(state: &ApiServerState) {
-    if let Ok(mut process_guard) = state.process.lock() {
-        if let Some(mut process) = process_guard.take() {
-            let _ = process.kill();
+fn restart_in_place(app: &AppHandle) -> Result<bool, String> {
+    let state = app.state::<ApiServerState>();
+    let config = app.state::<ConfigStore>().api();
+    let client = app.state::<ApiClient>();
+
+    let _span = tracing::info_span!("restart_api_server").entered();
+
+    // stop_api_server polls try_wait for the grace period before killing
+    stop_api_server(&state, config.shutdown_grace());
+
+    {
+        let mut process_guard = state.lock_process();
+        if process_guard.is_none() {
+            let progress = |progress| startup::report_progress(app, progress);
+            let started = start_api_server(&config, &client, &app.state::<ServerLogs>(), &progress)
+                .map_err(|e| e.to_string())?;
+            state.record_start(&started);
+            *process_guard = Some(started.process);
         }
     }
+
+    // Healthy once started, but give a slow first request the splash timeout to recover
+    let deadline = Instant::now() + config.splash_timeout();
+    tauri::async_runtime::block_on(async {
+        loop {
+            if health::probe(&client, &config).await {
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                return Ok(false);
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+    })
+}
+
+// Tauri command: Stop a leftover AEGIS server holding the API port. Other programs are
+// never killed; their name comes back so the UI can tell the user what to close.
+#[tauri::command]
+async fn free_api_port(app: AppHandle) -> Result<FreePortOutcome, String> {
+    tauri::async_runtime::spawn_blocking(move || stale_server::free_api_port(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Node processes that look like AEGIS servers (spawned with AEGIS_API_PORT
+// or run from an AEGIS checkout) other than the managed one: [{ pid, name, port, command }]
+#[tauri::command]
+async fn find_orphaned_backends(app: AppHandle) -> Result<Vec<OrphanedBackend>, String> {
+    tauri::async_runtime::spawn_blocking(move || stale_server::find_orphaned_backends(&app))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// Tauri command: After the user confirms, stop every orphaned AEGIS server. Returns the
+// PIDs stopped, or null if cancelled; the managed server is never touched.
+#[tauri::command]
+async fn kill_orphaned_backends(app: AppHandle) -> Result<Option<Vec<u32>>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let orphans = stale_server::find_orphaned_backends(&app);
+        if orphans.is_empty() {
+            return Some(Vec::new());
+        }
+        let confirmed = app
+            .dialog()
+            .message(format!(
+                "Stop {} leftover AEGIS server process(es)? The server AEGIS is running now \
+                 is not affected.",
+                orphans.len()
+            ))
+            .title("Stop leftover servers")
+            .kind(MessageDialogKind::Warning)
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                "Stop".into(),
+                "Cancel".into(),
+            ))
+            .blocking_show();
+        confirmed.then(|| stale_server::kill_orphaned_backends(&app))
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+// Tauri command: Check whether a port is free before it is saved, naming the process that
+// holds it otherwise. Ports below 1024 or above 65535 are an error.
+#[tauri::command]
+async fn test_port(port: u32) -> Result<PortTest, String> {
+    tauri::async_runtime::spawn_blocking(move || stale_server::test_port(port))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Stop everything, wait for the port to be released and start again
+#[tauri::command]
+async fn recover(app: AppHandle) -> Result<HealthReport, String> {
+    tauri::async_runtime::spawn_blocking(move || recovery::recover(&app))
+        .await
+        .map_err(|e| e.to_string())?
 }
 
 // Tauri command: Check if API is running
 #[tauri::command]
-async fn check_api_health() -> Result<bool, String> {
-    match reqwest::get("http://localhost:4243/api/health").await {
-        Ok(response) => Ok(response.status().is_success()),
-        Err(_) => Ok(false),
+async fn check_api_health(
+    client: State<'_, ApiClient>,
+    config: State<'_, ConfigStore>,
+    breaker: State<'_, CircuitBreaker>,
+) -> Result<bool, CommandError> {
+    let (client, api) = (client.inner(), &config.api());
+    let result = breaker
+        .call(|| async move {
+            let report = health::check(client, api).await;
+            if report.reachable {
+                Ok(report)
+            } else {
+                Err(CommandError::NotRunning(report.error.unwrap_or_default()))
+            }
+        })
+        .await;
+    match result {
+        Ok(report) => {
+            tracing::debug!(
+                request_id = report.request_id.as_deref(),
+                healthy = report.is_healthy(),
+                error = report.error.as_deref(),
+                "check_api_health"
+            );
+            Ok(report.is_healthy())
+        }
+        Err(e) => {
+            tracing::debug!(error = %e, "check_api_health");
+            Ok(false)
+        }
     }
 }
 
+// Tauri command: Circuit breaker state behind check_api_health and get_system_status:
+// { state: 'closed' | 'open' | 'half_open', consecutive_failures, retry_in_ms }
+#[tauri::command]
+fn get_backend_breaker(breaker: State<CircuitBreaker>) -> Result<BreakerStatus, String> {
+    Ok(breaker.status())
+}
+
+// Tauri command: Check the API with status code, latency and error details
+#[tauri::command]
+async fn check_api_health_detailed(
+    client: State<'_, ApiClient>,
+    config: State<'_, ConfigStore>,
+) -> Result<HealthReport, String> {
+    Ok(health::check(&client, &config.api()).await)
+}
+
+// Tauri command: Node.js and npm versions, the dashboard, the API port, aegis.toml, the log
+// and config directories and notifications, each as { name, passed, critical, detail }
+#[tauri::command]
+async fn environment_report(app: AppHandle) -> Result<Vec<EnvironmentCheck>, String> {
+    tauri::async_runtime::spawn_blocking(move || environment::report(&app))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// Tauri command: Networking checklist for a backend that won't connect. The internet
+// check is opt-in; each check times out on its own and they run in parallel.
+#[tauri::command]
+async fn run_connectivity_test(
+    include_internet: bool,
+    client: State<'_, ApiClient>,
+    config: State<'_, ConfigStore>,
+) -> Result<Vec<CheckResult>, String> {
+    Ok(connectivity::run(&client, &config.api(), include_internet).await)
+}
+
+// Tauri command: The last 100 background health checks, oldest first
+#[tauri::command]
+fn get_health_history(poller: State<HealthPollerState>) -> Vec<HealthSample> {
+    poller.history()
+}
+
 // Tauri command: Get system status
 #[tauri::command]
-async fn get_system_status() -> Result<String, String> {
-    match reqwest::get("http://localhost:4243/api/status").await {
-        Ok(response) => response.text().await.map_err(|e| e.to_string()),
-        Err(e) => Err(e.to_string()),
-    }
+async fn get_system_status(
+    client: State<'_, ApiClient>,
+    config: State<'_, ConfigStore>,
+    breaker: State<'_, CircuitBreaker>,
+) -> Result<SystemStatus, CommandError> {
+    let (client, api) = (client.inner(), &config.api());
+    breaker.call(|| status::fetch(client, api)).await
+}
+
+// Tauri command: Get system status as the unparsed response body
+#[tauri::command]
+async fn get_system_status_raw(
+    client: State<'_, ApiClient>,
+    config: State<'_, ConfigStore>,
+) -> Result<String, String> {
+    Ok(status::fetch_raw(&client, &config.api()).await?)
+}
+
+// Tauri command: Whether the API server runs on the bundled or the system Node.js
+#[tauri::command]
+fn get_node_source(config: State<ConfigStore>) -> Result<NodeSource, String> {
+    runtime::resolve(config.api().resource_dir.as_deref())
+        .map(|node_runtime| node_runtime.source)
+        .map_err(|program| format!("{} was not found on PATH", program))
+}
+
+// Tauri command: App version, build metadata, backend version and runtime details
+#[tauri::command]
+async fn about_info(app: AppHandle) -> Result<AboutInfo, String> {
+    Ok(about::collect(&app).await)
+}
+
+// Tauri command: Ask the release feed whether a newer version exists
+#[tauri::command]
+async fn check_for_updates(app: AppHandle) -> Result<UpdateCheck, String> {
+    updates::check(&app).await
+}
+
+// Tauri command: Compare the running backend's version with the one this app expects
+// (api.expected_backend_version, or the app version). Only the major version must match.
+#[tauri::command]
+async fn check_version_compatibility(app: AppHandle) -> Result<VersionCompatibility, CommandError> {
+    version_check::check(&app).await
+}
+
+// Tauri command: Download and verify the available update, then stop the backend and
+// install it. Relaunches on success, so a response only ever carries an error.
+#[tauri::command]
+async fn install_update(app: AppHandle) -> Result<(), String> {
+    updates::install(&app).await
 }
 
 // Tauri command: Start API server manually
 #[tauri::command]
-fn start_api(state: State<ApiServerState>) -> Result<bool, String> {
-    let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
-    if process_guard.is_none() {
-        *process_guard = start_api_server();
-        Ok(process_guard.is_some())
-    } else {
-        Ok(true) // Already running
+fn start_api(
+    app: AppHandle,
+    lockdown: State<LockdownState>,
+) -> Result<StartApiStatus, CommandError> {
+    lockdown.ensure_unlocked()?;
+    safe_mode::exit(&app);
+    let state = app.state::<ApiServerState>();
+    // A held lock means a start, stop or restart is in progress; don't wait on it here
+    if state
+        .try_lock_process()
+        .is_some_and(|process| process.is_some())
+    {
+        return Ok(StartApiStatus::AlreadyRunning);
+    }
+    if !state.begin_start() {
+        return Ok(StartApiStatus::Starting);
     }
+
+    std::thread::spawn(move || {
+        let state = app.state::<ApiServerState>();
+        let client = app.state::<ApiClient>();
+        let config = app.state::<ConfigStore>().api();
+        let mut process_guard = state.lock_process();
+        if process_guard.is_none() {
+            let progress = |progress| startup::report_progress(&app, progress);
+            match start_api_server(&config, &client, &app.state::<ServerLogs>(), &progress) {
+                Ok(started) => {
+                    state.record_start(&started);
+                    *process_guard = Some(started.process);
+                }
+                Err(e) => tracing::error!(error = %e, "start_api failed"),
+            }
+        }
+        drop(process_guard);
+        state.finish_start();
+    });
+    Ok(StartApiStatus::Starting)
+}
+
+// Tauri command: The dashboard directory the API server is started from and whether it
+// exists. Fails when no dashboard_path is set and the project root can't be derived.
+#[tauri::command]
+fn get_dashboard_path(config: State<ConfigStore>) -> Result<DashboardLocation, String> {
+    server::locate_dashboard(&config.api()).map_err(|e| e.to_string())
+}
+
+// Tauri command: Check the dashboard package npm will run: that it exists, has the dev and
+// prod server scripts and has its dependencies installed
+#[tauri::command]
+fn validate_backend_install(config: State<ConfigStore>) -> Result<InstallReport, String> {
+    let dashboard_path =
+        server::resolve_dashboard_path(&config.api()).map_err(|e| e.to_string())?;
+    Ok(backend_install::inspect(&dashboard_path))
+}
+
+// Tauri command: The configured proxy override, the proxy picked up from the environment
+// and whether the (local) base URL bypasses proxies
+#[tauri::command]
+fn get_proxy(config: State<ConfigStore>) -> ProxySettings {
+    client::proxy_settings(&config.api())
+}
+
+// Tauri command: Save a proxy for a remote api_base_url (null for the system proxy) and
+// rebuild the HTTP client with it. Local base URLs are never proxied.
+#[tauri::command]
+fn set_proxy(
+    proxy_url: Option<String>,
+    config: State<ConfigStore>,
+    client: State<ApiClient>,
+) -> Result<(), String> {
+    let api = config.set_proxy_url(proxy_url.filter(|url| !url.trim().is_empty()))?;
+    client.rebuild(&api)
 }
 
 // Tauri command: Stop API server
 #[tauri::command]
-fn stop_api(state: State<ApiServerState>) -> Result<(), String> {
-    stop_api_server(&state);
+async fn stop_api(app: AppHandle) -> Result<(), CommandError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let grace = app.state::<ConfigStore>().api().shutdown_grace();
+        stop_api_server(&app.state::<ApiServerState>(), grace);
+    })
+    .await
+    .map_err(|e| CommandError::Backend(e.to_string()))
+}
+
+// Tauri command: Restart API server, returning whether it is healthy afterwards
+#[tauri::command]
+async fn restart_api(app: AppHandle) -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(move || restart_api_server(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Switch the API server between its dev and prod npm scripts and restart it.
+// Returns whether the restarted server is healthy.
+#[tauri::command]
+async fn set_server_mode(app: AppHandle, mode: ServerMode) -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        app.state::<ConfigStore>()
+            .update(|saved| saved.api.server_mode = Some(mode))?;
+        tracing::info!(script = mode.script(), "server mode changed");
+        restart_api_server(&app)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Replace the Node.js flags (e.g. `--max-old-space-size=2048`) and restart
+// the API server with them. Returns whether the restarted server is healthy.
+#[tauri::command]
+async fn set_node_flags(app: AppHandle, flags: Vec<String>) -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        app.state::<ConfigStore>()
+            .update(|saved| saved.api.node_flags = flags)?;
+        restart_api_server(&app)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Run a different backend profile from aegis.toml (null for plain [api]).
+// Stops the server, starts the profile's one under a new watchdog and reports its health.
+#[tauri::command]
+async fn switch_profile(app: AppHandle, name: Option<String>) -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(move || profiles::switch(&app, name))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Spawn-to-ready time and attempts of the most recent server start,
+// or null before one has succeeded
+#[tauri::command]
+fn get_startup_metrics(state: State<ApiServerState>) -> Option<StartupMetrics> {
+    state.last_start()
+}
+
+// Tauri command: PID of the managed API server, if one is running. Never waits on the
+// process lock: during a start or stop it returns the PID recorded last.
+#[tauri::command]
+fn get_api_pid(state: State<ApiServerState>) -> Result<Option<u32>, String> {
+    Ok(state.pid())
+}
+
+// Tauri command: Epoch milliseconds at which the managed API server became healthy, reset by
+// every restart; null when none is running
+#[tauri::command]
+fn get_api_start_time(state: State<ApiServerState>) -> Option<i64> {
+    state.started_at_ms()
+}
+
+// Tauri command: Whether the server state has had to recover from a panicked thread
+#[tauri::command]
+fn get_state_health(state: State<ApiServerState>) -> StateHealth {
+    state.health()
+}
+
+// Tauri command: Copy `pid=1234 port=4243 source=system healthy=true` to the clipboard for
+// support threads, returning the copied line
+#[tauri::command]
+async fn copy_debug_line(
+    app: AppHandle,
+    state: State<'_, ApiServerState>,
+    config: State<'_, ConfigStore>,
+    client: State<'_, ApiClient>,
+) -> Result<String, String> {
+    let pid = get_api_pid(state)?.map_or_else(|| "none".to_string(), |pid| pid.to_string());
+    let api = config.api();
+    let source = match runtime::resolve(api.resource_dir.as_deref()) {
+        Ok(node_runtime) => node_runtime.source.as_str(),
+        Err(_) => "missing",
+    };
+    let healthy = health::probe(&client, &api).await;
+
+    let line = format!(
+        "pid={} port={} source={} healthy={}",
+        pid, api.port, source, healthy
+    );
+    app.clipboard()
+        .write_text(line.as_str())
+        .map_err(|e| format!("Could not copy to the clipboard: {}", e))?;
+    Ok(line)
+}
+
+// Tauri command: CPU, memory and uptime of the managed API server, or None when it isn't running
+#[tauri::command]
+async fn get_api_resource_usage(app: AppHandle) -> Result<Option<ResourceUsage>, String> {
+    resources::server_usage(app).await
+}
+
+// Tauri command: Save a zip of config, recent logs, status and versions for a bug report.
+// Secrets and the home directory are redacted. Returns the saved path, or null if cancelled.
+#[tauri::command]
+async fn export_diagnostics(app: AppHandle) -> Result<Option<String>, String> {
+    let path = diagnostics::export(&app).await?;
+    Ok(path.map(|path| path.to_string_lossy().into_owned()))
+}
+
+// Tauri command: Bearer token the API server expects, for the dashboard's own requests.
+// Only handed to the app's windows, and not while locked down.
+#[tauri::command]
+fn get_api_token(
+    webview: tauri::Webview,
+    client: State<ApiClient>,
+    lockdown: State<LockdownState>,
+) -> Result<String, String> {
+    lockdown.ensure_unlocked()?;
+    if !windows::is_app_webview(&webview) {
+        tracing::warn!(
+            label = webview.label(),
+            "api token requested by untrusted webview"
+        );
+        return Err("The API token is only available to the AEGIS dashboard".into());
+    }
+    Ok(client.token().to_string())
+}
+
+// Tauri command: Whether automatic restarts gave up after a crash loop, and why.
+// start_api, restart_api and recover leave safe mode.
+#[tauri::command]
+fn get_safe_mode(safe_mode: State<SafeModeState>) -> SafeModeStatus {
+    safe_mode.status()
+}
+
+// Tauri command: Stop the watchdog restarting the API server, e.g. while it is being run by
+// hand. It resumes by itself after `minutes` (default 30). Health checks keep running.
+#[tauri::command]
+fn pause_watchdog(minutes: Option<u64>, watchdog: State<WatchdogState>) -> PauseStatus {
+    let duration = minutes
+        .map(|minutes| Duration::from_secs(minutes.saturating_mul(60)))
+        .unwrap_or(watchdog::DEFAULT_PAUSE);
+    watchdog.pause(duration);
+    watchdog.pause_status()
+}
+
+// Tauri command: Let the watchdog restart the API server again
+#[tauri::command]
+fn resume_watchdog(watchdog: State<WatchdogState>) {
+    watchdog.resume();
+}
+
+// Tauri command: Whether the watchdog is paused and for how many more seconds
+#[tauri::command]
+fn get_watchdog_status(watchdog: State<WatchdogState>) -> PauseStatus {
+    watchdog.pause_status()
+}
+
+// Tauri command: Number of automatic restarts performed by the watchdog
+#[tauri::command]
+fn get_restart_count(watchdog: State<WatchdogState>) -> u32 {
+    watchdog.restart_count.load(Ordering::SeqCst)
+}
+
+// Tauri command: Recent API server stdout/stderr lines, oldest first. The optional filter
+// (query, regex, stream, limit) is applied here; an invalid regex is an error.
+#[tauri::command]
+fn get_server_logs(
+    filter: Option<LogFilter>,
+    logs: State<ServerLogs>,
+) -> Result<Vec<LogLine>, String> {
+    logs.filtered(&filter.unwrap_or_default())
+}
+
+// Tauri command: Copy the lines get_server_logs would return for this filter, one per
+// line. Returns how many were copied.
+#[tauri::command]
+fn copy_server_logs(
+    app: AppHandle,
+    filter: Option<LogFilter>,
+    logs: State<ServerLogs>,
+) -> Result<usize, String> {
+    let lines = logs.filtered(&filter.unwrap_or_default())?;
+    let text: Vec<&str> = lines.iter().map(|line| line.text.as_str()).collect();
+    app.clipboard()
+        .write_text(text.join("\n"))
+        .map_err(|e| e.to_string())?;
+    Ok(lines.len())
+}
+
+// Tauri command: Empty the in-memory log buffer; the log file keeps its history
+#[tauri::command]
+fn clear_server_logs(logs: State<ServerLogs>) {
+    logs.clear();
+    tracing::info!("server log buffer cleared");
+}
+
+// Tauri command: Switch the backend to error, warn, info, debug or trace until AEGIS quits,
+// live when the server supports it and by restarting it otherwise
+#[tauri::command]
+async fn set_backend_log_level(
+    app: AppHandle,
+    level: String,
+) -> Result<BackendLogLevel, CommandError> {
+    log_level::set(&app, &level).await
+}
+
+// Tauri command: The level the backend runs with, for the log viewer; level is null when
+// the server's own default applies
+#[tauri::command]
+fn get_backend_log_level(config: State<ConfigStore>) -> BackendLogLevel {
+    BackendLogLevel {
+        level: log_level::current(&config.api()),
+        applied: None,
+    }
+}
+
+// Tauri command: Path of the active log file, if file logging could be set up
+#[tauri::command]
+fn get_log_file_path(logs: State<ServerLogs>) -> Option<String> {
+    logs.file()
+        .map(|file| file.path().to_string_lossy().into_owned())
+}
+
+// Reveal the log directory in the platform file manager
+fn open_logs_folder(app: &AppHandle) {
+    let Some(file) = app.state::<ServerLogs>().file().cloned() else {
+        notify(app, "AEGIS file logging is unavailable.");
+        return;
+    };
+    // Shell::open is deprecated in favour of tauri-plugin-opener, which this app doesn't ship yet
+    #[allow(deprecated)]
+    let result = app.shell().open(file.dir().to_string_lossy(), None);
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "could not open logs folder");
+    }
+}
+
+// Open the API base URL in the default browser, refusing while the backend is down
+async fn open_dashboard_in_browser(app: &AppHandle) -> Result<(), String> {
+    let config = app.state::<ConfigStore>().api();
+    let url = config.base_url();
+    if !health::probe(&app.state::<ApiClient>(), &config).await {
+        return Err(format!("The AEGIS backend at {} is not responding", url));
+    }
+
+    // Shell::open is deprecated in favour of tauri-plugin-opener, which this app doesn't ship yet
+    #[allow(deprecated)]
+    let result = app.shell().open(url, None);
+    result.map_err(|e| e.to_string())
+}
+
+// Tauri command: Open the dashboard in the system browser
+#[tauri::command]
+async fn open_in_browser(app: AppHandle) -> Result<(), String> {
+    open_dashboard_in_browser(&app).await
+}
+
+// Tauri command: Choose whether closing the window hides to tray (true) or quits (false)
+#[tauri::command]
+fn set_close_behavior(close_to_tray: bool, config: State<ConfigStore>) -> Result<(), String> {
+    config.update_preferences(|prefs| prefs.close_to_tray = close_to_tray)
+}
+
+// Tauri command: Choose whether quitting asks first while the backend is busy
+#[tauri::command]
+fn set_quit_confirm_enabled(enabled: bool, config: State<ConfigStore>) -> Result<(), String> {
+    config.update_preferences(|prefs| prefs.quit_confirm_enabled = enabled)
+}
+
+// Tauri command: Choose whether launch shows the splash or opens the dashboard at once
+#[tauri::command]
+fn set_splash_enabled(enabled: bool, config: State<ConfigStore>) -> Result<(), String> {
+    config.update_preferences(|prefs| prefs.splash_enabled = enabled)
+}
+
+// Tauri command: Pin the main window above other windows (or unpin it); kept across launches
+#[tauri::command]
+fn set_always_on_top(app: AppHandle, enabled: bool) -> Result<(), String> {
+    windows::set_always_on_top(&app, enabled)
+}
+
+// Tauri command: Hide the main window when it loses focus, after delay_ms (kept as is
+// when omitted); focusing or showing it again first cancels the hide
+#[tauri::command]
+fn set_auto_hide_on_blur(
+    enabled: bool,
+    delay_ms: Option<u64>,
+    config: State<ConfigStore>,
+) -> Result<(), String> {
+    config.update_preferences(|prefs| {
+        prefs.auto_hide_on_blur = enabled;
+        if let Some(delay_ms) = delay_ms {
+            prefs.auto_hide_delay_ms = delay_ms;
+        }
+    })
+}
+
+// Tauri command: Whether the main window is kept above other windows
+#[tauri::command]
+fn is_always_on_top(app: AppHandle) -> bool {
+    windows::always_on_top(&app)
+}
+
+// Tauri command: Choose whether AEGIS launches hidden in the tray
+#[tauri::command]
+fn set_start_minimized(start_minimized: bool, config: State<ConfigStore>) -> Result<(), String> {
+    config.update_preferences(|prefs| prefs.start_minimized = start_minimized)
+}
+
+// Tauri command: Seconds between background health checks, applied to the running poller.
+// 0 stops polling and the tray shows the status as unknown.
+#[tauri::command]
+fn set_health_poll_interval(
+    app: AppHandle,
+    secs: u64,
+    config: State<ConfigStore>,
+) -> Result<(), String> {
+    config.update(|saved| saved.api.health_poll_interval_secs = secs)?;
+    health_poller::wake(&app);
+    Ok(())
+}
+
+// Tauri command: Copy a secret to the clipboard. With auto-clear on, it is wiped after
+// clipboard_clear_secs unless something else was copied in the meantime.
+#[tauri::command]
+fn copy_sensitive(app: AppHandle, text: String) -> Result<(), String> {
+    clipboard::copy_sensitive(&app, text)
+}
+
+// Tauri command: Copy a readable summary of get_system_status to the clipboard. Follows the
+// clipboard auto-clear preference like copy_sensitive.
+#[tauri::command]
+async fn copy_status(
+    app: AppHandle,
+    client: State<'_, ApiClient>,
+    config: State<'_, ConfigStore>,
+) -> Result<(), String> {
+    let status = status::fetch(&client, &config.api()).await?;
+    clipboard::copy_sensitive(&app, status.to_text())
+}
+
+// Tauri command: Opt in or out of clearing sensitive copies, optionally changing the delay
+#[tauri::command]
+fn set_clipboard_auto_clear(
+    enabled: bool,
+    secs: Option<u64>,
+    config: State<ConfigStore>,
+) -> Result<(), String> {
+    config.update_preferences(|prefs| {
+        prefs.clipboard_auto_clear = enabled;
+        if let Some(secs) = secs {
+            prefs.clipboard_clear_secs = secs;
+        }
+    })
+}
+
+// Tauri command: Rebind the global show/hide shortcut and remember it
+#[tauri::command]
+fn set_toggle_hotkey(
+    app: AppHandle,
+    accelerator: String,
+    config: State<ConfigStore>,
+) -> Result<(), String> {
+    hotkeys::set_hotkey(&app, HotkeyAction::ToggleWindow, &accelerator)?;
+    config.update_preferences(|prefs| prefs.toggle_hotkey = accelerator)
+}
+
+// Tauri command: Rebind the global lockdown shortcut and remember it
+#[tauri::command]
+fn set_lockdown_hotkey(
+    app: AppHandle,
+    accelerator: String,
+    config: State<ConfigStore>,
+) -> Result<(), String> {
+    hotkeys::set_hotkey(&app, HotkeyAction::Lockdown, &accelerator)?;
+    config.update_preferences(|prefs| prefs.lockdown_hotkey = accelerator)
+}
+
+// Tauri command: Turn encryption of aegis.toml on or off; the file is rewritten right away.
+// The key lives in the OS keychain and is created the first time it is needed.
+#[tauri::command]
+fn set_config_encryption(enabled: bool, config: State<ConfigStore>) -> Result<(), String> {
+    config
+        .update(|saved| saved.encrypt_at_rest = enabled)
+        .map(|_| ())
+}
+
+// Tauri command: The configuration as saved in aegis.toml
+#[tauri::command]
+fn get_config(config: State<ConfigStore>) -> Config {
+    config.get()
+}
+
+// Tauri command: Validate and save a new configuration. Hotkeys are rebound immediately;
+// returns the changed fields (port, base URL, CA, timeout) that need a restart to apply.
+#[tauri::command]
+fn update_config(
+    app: AppHandle,
+    new_config: Config,
+    config: State<ConfigStore>,
+) -> Result<Vec<&'static str>, String> {
+    new_config.validate()?;
+    rebind_changed_hotkeys(&app, &config.preferences(), &new_config.preferences)?;
+    let theme = new_config.preferences.theme;
+    let profiles_changed = config.get().profiles != new_config.profiles;
+    let pending = config.update(|saved| *saved = new_config)?;
+    theme::apply(&app, theme);
+    health_poller::wake(&app);
+    if profiles_changed {
+        refresh_tray_menu(&app);
+    }
+    Ok(pending)
+}
+
+// Register the global shortcuts that differ between two sets of preferences
+fn rebind_changed_hotkeys(
+    app: &AppHandle,
+    current: &Preferences,
+    next: &Preferences,
+) -> Result<(), String> {
+    for (action, before, after) in [
+        (
+            HotkeyAction::ToggleWindow,
+            &current.toggle_hotkey,
+            &next.toggle_hotkey,
+        ),
+        (
+            HotkeyAction::Lockdown,
+            &current.lockdown_hotkey,
+            &next.lockdown_hotkey,
+        ),
+    ] {
+        if before != after {
+            hotkeys::set_hotkey(app, action, after)?;
+        }
+    }
     Ok(())
 }
 
+// Tauri command: After the user confirms, back aegis.toml up to aegis.toml.bak and start
+// over from the defaults. Returns the fields that need a restart, or null if cancelled.
+#[tauri::command]
+async fn reset_config(app: AppHandle) -> Result<Option<Vec<&'static str>>, String> {
+    let dialog_app = app.clone();
+    let confirmed = tauri::async_runtime::spawn_blocking(move || {
+        dialog_app
+            .dialog()
+            .message(
+                "Reset all AEGIS settings to their defaults? The current aegis.toml is kept \
+                 as aegis.toml.bak.",
+            )
+            .title("Reset settings")
+            .kind(MessageDialogKind::Warning)
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                "Reset".into(),
+                "Cancel".into(),
+            ))
+            .blocking_show()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    if !confirmed {
+        return Ok(None);
+    }
+
+    let config = app.state::<ConfigStore>();
+    let before = config.preferences();
+    let pending = config.reset()?;
+    tracing::warn!(?pending, "config reset to defaults");
+    apply_replaced_preferences(&app, &before);
+    Ok(Some(pending))
+}
+
+// Bring hotkeys, theme, locale, polling and the tray in line after the whole config was
+// replaced (reset, import or an edit to aegis.toml on disk). The new config is saved
+// either way; a shortcut another app holds just stays unbound.
+fn apply_replaced_preferences(app: &AppHandle, before: &Preferences) {
+    let after = app.state::<ConfigStore>().preferences();
+    if let Err(e) = rebind_changed_hotkeys(app, before, &after) {
+        tracing::warn!(error = %e, "could not rebind hotkeys after config was replaced");
+    }
+    theme::apply(app, after.theme);
+    windows::apply_always_on_top(app);
+    if let Err(e) = app.state::<I18n>().set_locale(after.locale.as_deref()) {
+        tracing::warn!(error = %e, "could not reload locale after config was replaced");
+    }
+    health_poller::wake(app);
+    refresh_tray_menu(app);
+}
+
+// Tauri command: Config, health history, watchdog counters and mode flags as one JSON
+// object for support or test setup. The API token and server process are left out.
+#[tauri::command]
+fn export_state(app: AppHandle) -> AppSnapshot {
+    snapshot::export(&app)
+}
+
+// Tauri command: Restore an export_state snapshot. Issues a new API token and restarts a
+// running server. Returns the config fields that need an app restart.
+#[tauri::command]
+async fn import_state(app: AppHandle, snapshot: AppSnapshot) -> Result<Vec<&'static str>, String> {
+    tauri::async_runtime::spawn_blocking(move || snapshot::import(&app, snapshot))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// Tauri command: Switch the tray menu and dialogs to a locale such as `de`; null follows the OS.
+// Returns the locale actually loaded.
+#[tauri::command]
+fn set_locale(
+    app: AppHandle,
+    locale: Option<String>,
+    config: State<ConfigStore>,
+    i18n: State<I18n>,
+) -> Result<String, String> {
+    let loaded = i18n.set_locale(locale.as_deref())?;
+    config.update_preferences(|prefs| prefs.locale = locale)?;
+    refresh_tray_menu(&app);
+    tracing::info!(locale = %loaded, "locale changed");
+    Ok(loaded)
+}
+
+// Tauri command: Locale the tray menu and dialogs are currently shown in
+#[tauri::command]
+fn get_locale(i18n: State<I18n>) -> String {
+    i18n.locale()
+}
+
+// Tauri command: Switch between system, light and dark themes and remember the choice
+#[tauri::command]
+fn set_theme(app: AppHandle, theme: ThemePreference) -> Result<(), String> {
+    theme::set(&app, theme)
+}
+
+// Tauri command: Hide every window and kill the API server immediately
+#[tauri::command]
+fn lockdown(app: AppHandle) {
+    lockdown::engage(&app);
+}
+
+// Tauri command: Leave lockdown so start_api works again
+#[tauri::command]
+fn unlock(app: AppHandle) {
+    lockdown::release(&app);
+}
+
+// Tauri command: Whether lockdown is engaged
+#[tauri::command]
+fn is_locked_down(lockdown: State<LockdownState>) -> bool {
+    lockdown.is_active()
+}
+
+// Tauri command: Launch AEGIS (to the tray) when the user logs in
+#[tauri::command]
+fn enable_autostart(app: AppHandle) -> Result<(), String> {
+    autostart::set_enabled(&app, true)
+}
+
+// Tauri command: Stop launching AEGIS at login
+#[tauri::command]
+fn disable_autostart(app: AppHandle) -> Result<(), String> {
+    autostart::set_enabled(&app, false)
+}
+
+// Tauri command: Whether AEGIS is registered to launch at login
+#[tauri::command]
+fn is_autostart_enabled(app: AppHandle) -> Result<bool, String> {
+    autostart::is_enabled(&app)
+}
+
+// Tauri command: Whether OS notifications work here. When they don't (no notification
+// service on some Linux desktops), notifications arrive as notification-banner events.
+#[tauri::command]
+fn notifications_supported(app: AppHandle) -> bool {
+    notifications::supported(&app)
+}
+
+// Tauri command: Whether the main window is on screen (shown and not minimized), e.g. to
+// pick an in-app toast over an OS notification. See window-visibility-changed.
+#[tauri::command]
+fn is_window_visible(app: AppHandle) -> Result<bool, String> {
+    Ok(windows::is_main_window_visible(&app))
+}
+
+// Tauri command: Show the main window at a dashboard route such as `/status`
+#[tauri::command]
+fn navigate(app: AppHandle, route: String) -> Result<(), String> {
+    show_main_window(&app);
+    navigate_main_window(&app, &route)
+}
+
+// Tauri command: Open `route` on the API base URL in its own window, e.g. the logs for a
+// support session. Calling it again with the same label focuses that window.
+// Async for the same reason as open_settings.
+#[tauri::command]
+async fn open_route_window(app: AppHandle, route: String, label: String) -> Result<(), String> {
+    windows::open_route_window(&app, &route, &label)
+}
+
+// Tauri command: Mark the first-run walkthrough as done (finished or skipped)
+#[tauri::command]
+fn complete_onboarding(config: State<ConfigStore>) -> Result<(), String> {
+    config.update_preferences(|prefs| prefs.onboarding_completed = true)
+}
+
+// Tauri command: Show onboarding again on the next launch (testing aid, not linked from the UI)
+#[tauri::command]
+fn reset_onboarding(config: State<ConfigStore>) -> Result<(), String> {
+    config.update_preferences(|prefs| prefs.onboarding_completed = false)
+}
+
+// Tauri command: Open (or focus) the settings window.
+// Async because creating a window from a sync command can deadlock on Windows.
+#[tauri::command]
+async fn open_settings(app: AppHandle) -> Result<(), String> {
+    open_settings_window(&app).map_err(|e| e.to_string())
+}
+
 fn main() {
+    let context = tauri::generate_context!();
+
+    // `aegis status|restart|stop` talk to the running instance instead of launching a new one
+    if let Some(command) = cli::subcommand() {
+        std::process::exit(cli::run(command, &context.config().identifier));
+    }
+
+    let logs = ServerLogs::default();
+    telemetry::init(&logs);
+
     tauri::Builder::default()
+        // Must be registered first: a second launch hands off to us and exits before setup runs
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            show_main_window(app);
+        }))
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_notification::init())
-        .manage(ApiServerState {
-            process: Mutex::new(None),
-        })
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(autostart::plugin())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(hotkeys::plugin())
+        .manage(ApiServerState::default())
+        .manage(WatchdogState::default())
+        .manage(SafeModeState::default())
+        .manage(logs)
+        .manage(HotkeyState::default())
+        .manage(LockdownState::default())
+        .manage(ResourceMonitor::default())
+        .manage(TrayMenu::default())
+        .manage(TrayClickState::default())
+        .manage(MainWindowVisibility::default())
+        .manage(AutoHideState::default())
+        .manage(RouteWindows::default())
+        .manage(CircuitBreaker::default())
+        .manage(ConfigWatcherState::default())
+        .manage(VersionState::default())
+        .manage(HealthPollerState::default())
+        .manage(ClipboardState::default())
+        .manage(NotificationManager::default())
         .setup(|app| {
-            // Start API server on app launch
-            let state = app.state::<ApiServerState>();
-            if let Ok(mut process_guard) = state.process.lock() {
-                *process_guard = start_api_server();
-                if process_guard.is_some() {
-                    println!("AEGIS API server started on localhost:4243");
-                } else {
-                    println!("Warning: Could not start API server automatically");
+            // Mirror server output and lifecycle events to disk before anything is spawned
+            match app.path().app_log_dir() {
+                Ok(dir) => match LogFile::open(&dir) {
+                    Ok(file) => app.state::<ServerLogs>().attach_file(file),
+                    Err(e) => {
+                        tracing::warn!(dir = %dir.display(), error = %e, "could not open log file")
+                    }
+                },
+                Err(e) => tracing::warn!(error = %e, "could not resolve log directory"),
+            }
+            app.state::<ServerLogs>().attach_frontend(app.handle().clone());
+            tracing::info!(version = %app.package_info().version, "aegis desktop starting");
+            notifications::probe(app.handle().clone());
+
+            // Load API server config before anything needs the port
+            let config = ConfigStore::load(
+                app.path().app_config_dir().ok().as_deref(),
+                app.path().resource_dir().ok(),
+            );
+            let api_config = config.api();
+            let prefs = config.preferences();
+            tracing::info!(port = api_config.port, base_url = %api_config.base_url(), "config loaded");
+            let (client, client_error) = ApiClient::build_or_fallback(&api_config);
+            if let Some(e) = &client_error {
+                report_client_error(app.handle(), e);
+            }
+            app.manage(client);
+            app.manage(config);
+
+            hotkeys::register_from_preferences(app.handle(), &prefs);
+
+            // Tray and dialog strings; an explicit locale preference wins over the OS one
+            app.manage(I18n::new(
+                app.path().resource_dir().ok(),
+                prefs.locale.as_deref(),
+            ));
+
+            // Theme before any window is shown so there's no flash of the wrong one
+            theme::apply(app.handle(), prefs.theme);
+
+            // Saved geometry is restored as the (hidden) main window is created
+            app.manage(WindowStateStore::load(
+                app.path().app_config_dir().ok().as_deref(),
+            ));
+
+            // Headless runs don't create the main window until it is opened from the tray
+            let headless = cli::headless();
+            if !headless {
+                if let Err(e) = windows::create_main_window(app.handle()) {
+                    tracing::warn!(error = %e, "could not create main window");
                 }
             }
 
-            // Create system tray menu
-            let quit = MenuItem::with_id(app, "quit", "Quit AEGIS", true, None::<&str>)?;
-            let show = MenuItem::with_id(app, "show", "Show Dashboard", true, None::<&str>)?;
-            let status = MenuItem::with_id(app, "status", "System Status", true, None::<&str>)?;
+            // Show a splash while the backend boots; the health gate reveals the main window.
+            // Starting headless, minimized or from a login entry skips both and stays in the tray.
+            if headless || prefs.start_minimized || autostart::launched_at_login() {
+                tracing::info!(
+                    headless,
+                    start_minimized = prefs.start_minimized,
+                    "starting hidden in the tray"
+                );
+            } else if !prefs.splash_enabled {
+                // The dashboard covers the wait itself; a failed start still shows its banner
+                show_main_window(app.handle());
+            } else {
+                if let Err(e) = windows::open_splash_window(app.handle()) {
+                    tracing::warn!(error = %e, "could not open splash window");
+                }
+                startup::spawn_health_gate(app.handle().clone());
+            }
+            startup::spawn_api_server(app.handle().clone());
+
+            // One report of everything the backend needs, so a broken setup shows up in one place
+            environment::spawn_launch_check(app.handle().clone());
+
+            // Route first-time users to the walkthrough once the dashboard is listening
+            onboarding::watch(app.handle());
+
+            // Pick up hand edits to aegis.toml without a restart
+            config_watcher::spawn(app.handle().clone());
 
-            let menu = Menu::with_items(app, &[&show, &status, &quit])?;
+            // Restart the API server if it crashes
+            watchdog::spawn(app.handle().clone());
 
-            let _tray = TrayIconBuilder::new()
-                .menu(&menu)
-                .tooltip("AEGIS Privacy Suite")
+            // Accept `aegis status|restart|stop` from the command line
+            control::spawn(app.handle().clone());
+
+            // Ctrl-C or SIGTERM from a terminal still stops the API server
+            signals::spawn(app.handle().clone());
+
+            // Create system tray; the menu is attached below so it can be rebuilt per locale
+            let _tray = TrayIconBuilder::with_id(health_poller::TRAY_ID)
+                .icon(health_poller::tray_icon(false)?)
+                .tooltip(i18n::t(app.handle(), "tray.tooltip.default"))
                 .on_menu_event(|app, event| match event.id.as_ref() {
-                    "quit" => {
-                        // Stop API server before quitting
-                        let state = app.state::<ApiServerState>();
-                        stop_api_server(&state);
-                        app.exit(0);
+                    "quit" => request_quit(app),
+                    "show" => show_main_window(app),
+                    "browser" => {
+                        let app = app.clone();
+                        tauri::async_runtime::spawn(async move {
+                            if let Err(e) = open_dashboard_in_browser(&app).await {
+                                notify(&app, &format!("Could not open AEGIS in the browser: {}", e));
+                            }
+                        });
+                    }
+                    "restart" => {
+                        // Restarting blocks on the health check, so keep it off the event loop
+                        let app = app.clone();
+                        std::thread::spawn(move || {
+                            if let Err(e) = restart_api_server(&app) {
+                                notify(&app, &format!("AEGIS backend restart failed: {}", e));
+                            }
+                        });
                     }
-                    "show" => {
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.show();
-                            let _ = window.set_focus();
+                    "settings" => {
+                        if let Err(e) = open_settings_window(app) {
+                            tracing::warn!(error = %e, "could not open settings window");
+                        }
+                    }
+                    "logs" => open_logs_folder(app),
+                    "updates" => updates::check_from_tray(app),
+                    "about" => about::show_dialog(app),
+                    autostart::TRAY_ITEM_ID => {
+                        let enable = !autostart::is_enabled(app).unwrap_or(false);
+                        if let Err(e) = autostart::set_enabled(app, enable) {
+                            tracing::warn!(error = %e, "could not toggle autostart");
+                            autostart::sync_menu_item(app);
+                        }
+                    }
+                    windows::ALWAYS_ON_TOP_ITEM_ID => {
+                        let enable = !windows::always_on_top(app);
+                        if let Err(e) = windows::set_always_on_top(app, enable) {
+                            tracing::warn!(error = %e, "could not toggle always-on-top");
+                            windows::apply_always_on_top(app);
                         }
                     }
                     "status" => {
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                            let _ = window.eval("window.location.href = '/status'");
+                        show_main_window(app);
+                        let _ = navigate_main_window(app, "/status");
+                    }
+                    "copy_status" => {
+                        let app = app.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let result = copy_status(
+                                app.clone(),
+                                app.state::<ApiClient>(),
+                                app.state::<ConfigStore>(),
+                            )
+                            .await;
+                            match result {
+                                Ok(()) => notify(&app, "AEGIS status copied to the clipboard"),
+                                Err(e) => notify(&app, &format!("Could not copy status: {}", e)),
+                            }
+                        });
+                    }
+                    id => {
+                        if let Some(theme) = ThemePreference::from_menu_id(id) {
+                            if let Err(e) = theme::set(app, theme) {
+                                tracing::warn!(error = %e, "could not save theme");
+                            }
+                        } else if let Some(profile) = profiles::from_menu_id(id) {
+                            // Switching restarts the server, so keep it off the event loop
+                            let app = app.clone();
+                            std::thread::spawn(move || {
+                                if let Err(e) = profiles::switch(&app, profile) {
+                                    notify(&app, &format!("Could not switch profile: {}", e));
+                                }
+                            });
                         }
                     }
-                    _ => {}
                 })
                 .on_tray_icon_event(|tray, event| {
-                    if let TrayIconEvent::Click {
-                        button: MouseButton::Left,
-                        button_state: MouseButtonState::Up,
-                        ..
-                    } = event
-                    {
-                        let app = tray.app_handle();
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    }
+                    tray::handle_icon_event(tray.app_handle(), &event);
                 })
                 .build(app)?;
+            tray::set_menu(app.handle(), build_tray_menu(app.handle())?)?;
+
+            // Keep the tray icon in sync with backend health
+            health_poller::spawn(app.handle().clone());
 
             Ok(())
         })
         .on_window_event(|window, event| {
-            // Minimize to tray instead of closing
-            if let WindowEvent::CloseRequested { api, .. } = event {
-                let _ = window.hide();
-                api.prevent_close();
+            let save_state = || {
+                if window.label() == windows::MAIN_WINDOW {
+                    if let Some(store) = window.try_state::<WindowStateStore>() {
+                        store.capture(window);
+                    }
+                }
+            };
+
+            match event {
+                WindowEvent::Moved(_) => save_state(),
+                // Minimizing and restoring arrive as resizes
+                WindowEvent::Resized(_) => {
+                    save_state();
+                    windows::sync_visibility(window.app_handle());
+                }
+                WindowEvent::Focused(focused) if window.label() == windows::MAIN_WINDOW => {
+                    windows::sync_visibility(window.app_handle());
+                    windows::handle_main_focus(window.app_handle(), *focused);
+                }
+                WindowEvent::CloseRequested { api, .. }
+                    if window.label() == windows::MAIN_WINDOW =>
+                {
+                    save_state();
+                    let close_to_tray = window
+                        .try_state::<ConfigStore>()
+                        .map(|config| config.preferences().close_to_tray)
+                        .unwrap_or(true);
+
+                    if close_to_tray {
+                        // Minimize to tray instead of closing
+                        let _ = window.hide();
+                        api.prevent_close();
+                        windows::sync_visibility(window.app_handle());
+                    } else {
+                        api.prevent_close();
+                        request_quit(window.app_handle());
+                    }
+                }
+                WindowEvent::ThemeChanged(theme) if window.label() == windows::MAIN_WINDOW => {
+                    theme::handle_os_change(window.app_handle(), *theme);
+                }
+                _ => {}
             }
         })
         .invoke_handler(tauri::generate_handler![
             check_api_health,
+            get_backend_breaker,
+            check_api_health_detailed,
+            run_connectivity_test,
+            environment_report,
+            get_health_history,
             get_system_status,
+            get_system_status_raw,
             start_api,
+            validate_backend_install,
+            get_dashboard_path,
+            get_proxy,
+            set_proxy,
             stop_api,
+            restart_api,
+            switch_profile,
+            set_server_mode,
+            set_node_flags,
+            recover,
+            free_api_port,
+            find_orphaned_backends,
+            kill_orphaned_backends,
+            test_port,
+            get_api_pid,
+            get_api_start_time,
+            get_state_health,
+            copy_debug_line,
+            get_startup_metrics,
+            get_node_source,
+            about_info,
+            check_for_updates,
+            install_update,
+            check_version_compatibility,
+            get_restart_count,
+            get_safe_mode,
+            pause_watchdog,
+            resume_watchdog,
+            get_watchdog_status,
+            get_api_resource_usage,
+            export_diagnostics,
+            get_api_token,
+            get_server_logs,
+            copy_server_logs,
+            set_backend_log_level,
+            get_backend_log_level,
+            clear_server_logs,
+            get_log_file_path,
+            set_close_behavior,
+            set_start_minimized,
+            set_splash_enabled,
+            set_always_on_top,
+            is_always_on_top,
+            set_auto_hide_on_blur,
+            set_health_poll_interval,
+            copy_sensitive,
+            copy_status,
+            set_clipboard_auto_clear,
+            set_quit_confirm_enabled,
+            set_toggle_hotkey,
+            set_lockdown_hotkey,
+            set_theme,
+            set_locale,
+            get_locale,
+            get_config,
+            set_config_encryption,
+            update_config,
+            reset_config,
+            export_state,
+            import_state,
+            enable_autostart,
+            disable_autostart,
+            is_autostart_enabled,
+            lockdown,
+            unlock,
+            is_locked_down,
+            open_settings,
+            open_in_browser,
+            navigate,
+            open_route_window,
+            is_window_visible,
+            notifications_supported,
+            complete_onboarding,
+            reset_onboarding,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running AEGIS desktop application");
+        .build(context)
+        .expect("error while running AEGIS desktop application")
+        .run(|app, event| match event {
+            // The tray keeps the app alive when its last window closes, e.g. the settings
+            // window in a headless run. Explicit exits (Quit, app.exit) carry a code.
+            RunEvent::ExitRequested {
+                code: None, api, ..
+            } => api.prevent_exit(),
+            RunEvent::Exit => control::remove_endpoint(app),
+            _ => {}
+        });
 }
@@ -0,0 +1,157 @@
+// ==============================================================================
+// file_id: SOM-SCR-0066-v1.0.0
+// name: notifications.rs
+// description: AEGIS Desktop - Desktop notification helpers
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, notification]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use crate::i18n;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+// Sent instead of an OS notification when there is no notification service
+pub const BANNER_EVENT: &str = "notification-banner";
+
+// Recently shown notifications by body, so a flapping backend can't flood the desktop
+#[derive(Default)]
+pub struct NotificationManager {
+    recent: Mutex<HashMap<String, Shown>>,
+    // Set by `probe` when the desktop has no notification service
+    unavailable: AtomicBool,
+}
+
+#[derive(Clone, Serialize)]
+struct Banner {
+    message: String,
+}
+
+struct Shown {
+    at: Instant,
+    // Repeats held back since `at`; reported together when the window closes
+    suppressed: u32,
+}
+
+// Show a desktop notification. A repeat of one shown within notification_window_secs is
+// held back and the repeats are summed up in a single notification once the window ends.
+pub fn notify(app: &AppHandle, body: &str) {
+    let window = app
+        .try_state::<ConfigStore>()
+        .map(|config| Duration::from_secs(config.preferences().notification_window_secs))
+        .unwrap_or_default();
+    let Some(manager) = app.try_state::<NotificationManager>() else {
+        return show(app, body);
+    };
+    if window.is_zero() {
+        return show(app, body);
+    }
+    let Ok(mut recent) = manager.recent.lock() else {
+        return show(app, body);
+    };
+
+    let now = Instant::now();
+    recent.retain(|_, shown| shown.suppressed > 0 || now.duration_since(shown.at) < window);
+    if let Some(shown) = recent.get_mut(body) {
+        shown.suppressed += 1;
+        if shown.suppressed == 1 {
+            flush_after(app, body.to_string(), window - now.duration_since(shown.at));
+        }
+        tracing::debug!(
+            body,
+            suppressed = shown.suppressed,
+            "notification suppressed"
+        );
+        return;
+    }
+    recent.insert(
+        body.to_string(),
+        Shown {
+            at: now,
+            suppressed: 0,
+        },
+    );
+    drop(recent);
+    show(app, body);
+}
+
+// Report the repeats held back for `body`, which also starts a new window for it
+fn flush_after(app: &AppHandle, body: String, delay: Duration) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+
+        let suppressed = {
+            let manager = app.state::<NotificationManager>();
+            let Ok(mut recent) = manager.recent.lock() else {
+                return;
+            };
+            let Some(shown) = recent.get_mut(&body) else {
+                return;
+            };
+            let suppressed = shown.suppressed;
+            shown.at = Instant::now();
+            shown.suppressed = 0;
+            suppressed
+        };
+        // The count goes in first so braces in the body are never treated as placeholders
+        let count = (suppressed + 1).to_string();
+        let repeated = i18n::tf(
+            &app,
+            "notification.repeated",
+            &[("count", &count), ("message", &body)],
+        );
+        show(&app, &repeated);
+    });
+}
+
+// Check for a notification service off the main thread; until it answers, OS
+// notifications are assumed to work. Only Linux can lack one.
+pub fn probe(app: AppHandle) {
+    #[cfg(target_os = "linux")]
+    std::thread::spawn(move || match notify_rust::get_server_information() {
+        Ok(server) => tracing::debug!(server = %server.name, "notification service found"),
+        Err(e) => {
+            tracing::warn!(error = %e, "no notification service; using in-window banners");
+            app.state::<NotificationManager>()
+                .unavailable
+                .store(true, Ordering::SeqCst);
+        }
+    });
+    #[cfg(not(target_os = "linux"))]
+    let _ = app;
+}
+
+pub fn supported(app: &AppHandle) -> bool {
+    app.try_state::<NotificationManager>()
+        .is_none_or(|manager| !manager.unavailable.load(Ordering::SeqCst))
+}
+
+// An in-window banner when OS notifications are unavailable. Failures are ignored since
+// there is nowhere to report them.
+fn show(app: &AppHandle, body: &str) {
+    if !supported(app) {
+        let _ = app.emit(
+            BANNER_EVENT,
+            Banner {
+                message: body.to_string(),
+            },
+        );
+        return;
+    }
+    let _ = app
+        .notification()
+        .builder()
+        .title("AEGIS")
+        .body(body)
+        .show();
+}
@@ -0,0 +1,37 @@
+// ==============================================================================
+// file_id: SOM-SCR-0087-v1.0.0
+// name: onboarding.rs
+// description: AEGIS Desktop - First-run onboarding routing
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, onboarding]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use crate::windows::navigate_main_window;
+use tauri::{AppHandle, Listener, Manager};
+
+// Emitted by the dashboard once its navigate listener is registered
+pub const DASHBOARD_READY_EVENT: &str = "dashboard-ready";
+
+const ONBOARDING_ROUTE: &str = "/onboarding";
+
+// Send the dashboard to the onboarding page until the user finishes or skips it.
+// Waiting for the ready event means the route isn't emitted before anyone is listening;
+// backend startup is unaffected.
+pub fn watch(app: &AppHandle) {
+    let handle = app.clone();
+    app.listen(DASHBOARD_READY_EVENT, move |_| {
+        let completed = handle
+            .try_state::<ConfigStore>()
+            .is_none_or(|config| config.preferences().onboarding_completed);
+        if !completed {
+            if let Err(e) = navigate_main_window(&handle, ONBOARDING_ROUTE) {
+                tracing::warn!(error = %e, "could not open onboarding");
+            }
+        }
+    });
+}
@@ -0,0 +1,88 @@
+// ==============================================================================
+// file_id: SOM-SCR-0072-v1.0.0
+// name: preferences.rs
+// description: AEGIS Desktop - User preferences stored in aegis.toml
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, preferences]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::theme::ThemePreference;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_TOGGLE_HOTKEY: &str = "CommandOrControl+Shift+A";
+pub const DEFAULT_LOCKDOWN_HOTKEY: &str = "CommandOrControl+Shift+L";
+
+// Which left-click on the tray icon brings the main window up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrayClick {
+    #[default]
+    Single,
+    Double,
+}
+
+// The `[preferences]` table of aegis.toml
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    // Closing the main window hides it to the tray instead of quitting
+    pub close_to_tray: bool,
+    // First-run walkthrough finished or skipped
+    pub onboarding_completed: bool,
+    // Ask before quitting while the backend reports in-flight work
+    pub quit_confirm_enabled: bool,
+    // Launch hidden in the tray; the backend still starts
+    pub start_minimized: bool,
+    // Show the splash until the backend is healthy; off opens the main window straight away
+    pub splash_enabled: bool,
+    // Keep the main window above other windows
+    pub always_on_top: bool,
+    // Hide the main window auto_hide_delay_ms after it loses focus, unless focus comes back
+    pub auto_hide_on_blur: bool,
+    pub auto_hide_delay_ms: u64,
+    // single or double: the tray-icon click that shows the main window
+    pub tray_show_click: TrayClick,
+    // Global shortcut that shows/hides the main window
+    pub toggle_hotkey: String,
+    // Global shortcut that engages lockdown
+    pub lockdown_hotkey: String,
+    // Lockdown also wipes the clipboard
+    pub lockdown_clears_clipboard: bool,
+    // Text copied with copy_sensitive is cleared after clipboard_clear_secs
+    pub clipboard_auto_clear: bool,
+    pub clipboard_clear_secs: u64,
+    // Repeats of a notification within this many seconds are folded into one; 0 shows all
+    pub notification_window_secs: u64,
+    // system, light or dark
+    pub theme: ThemePreference,
+    // Tray and dialog language such as `de`; unset follows the OS
+    pub locale: Option<String>,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            close_to_tray: true,
+            start_minimized: false,
+            splash_enabled: true,
+            always_on_top: false,
+            auto_hide_on_blur: false,
+            auto_hide_delay_ms: 500,
+            tray_show_click: TrayClick::Single,
+            quit_confirm_enabled: true,
+            onboarding_completed: false,
+            toggle_hotkey: DEFAULT_TOGGLE_HOTKEY.into(),
+            lockdown_hotkey: DEFAULT_LOCKDOWN_HOTKEY.into(),
+            lockdown_clears_clipboard: false,
+            clipboard_auto_clear: false,
+            clipboard_clear_secs: 30,
+            notification_window_secs: 60,
+            theme: ThemePreference::System,
+            locale: None,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// ==============================================================================
+// file_id: SOM-SCR-0100-v1.0.0
+// name: profiles.rs
+// description: AEGIS Desktop - Switching between named backend profiles
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, config, tray]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use crate::lockdown::LockdownState;
+use crate::{health_poller, i18n, tray, watchdog};
+use tauri::menu::{CheckMenuItem, IsMenuItem, Submenu};
+use tauri::{AppHandle, Manager, Wry};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+// Tray ids are the prefix plus the profile name; the bare prefix is plain `[api]`
+const MENU_ID_PREFIX: &str = "profile:";
+
+fn menu_id(name: Option<&str>) -> String {
+    format!("{}{}", MENU_ID_PREFIX, name.unwrap_or_default())
+}
+
+// The profile behind a tray menu item id, if it is one of ours. Some(None) is the default.
+pub fn from_menu_id(id: &str) -> Option<Option<String>> {
+    let name = id.strip_prefix(MENU_ID_PREFIX)?;
+    Some((!name.is_empty()).then(|| name.to_string()))
+}
+
+// Build the tray's profile submenu with the active one checked; None when no profiles exist
+pub fn tray_submenu(app: &AppHandle) -> tauri::Result<Option<Submenu<Wry>>> {
+    let config = app.state::<ConfigStore>().get();
+    if config.profiles.is_empty() {
+        return Ok(None);
+    }
+
+    let default = i18n::t(app, "tray.profile.default");
+    let items = std::iter::once((None, default.as_str()))
+        .chain(
+            config
+                .profiles
+                .keys()
+                .map(|name| (Some(name.as_str()), name.as_str())),
+        )
+        .map(|(name, label)| {
+            CheckMenuItem::with_id(
+                app,
+                menu_id(name),
+                label,
+                true,
+                name == config.active_profile.as_deref(),
+                None::<&str>,
+            )
+        })
+        .collect::<tauri::Result<Vec<_>>>()?;
+
+    let entries: Vec<&dyn IsMenuItem<Wry>> = items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<Wry>)
+        .collect();
+    Submenu::with_id_and_items(
+        app,
+        "profiles",
+        i18n::t(app, "tray.profiles"),
+        true,
+        &entries,
+    )
+    .map(Some)
+}
+
+// Stop the server, make `name` the active profile (None for plain `[api]`) and start the
+// server it describes under a fresh watchdog. Returns whether the new backend is healthy.
+pub fn switch(app: &AppHandle, name: Option<String>) -> Result<bool, String> {
+    let previous = app.state::<ConfigStore>().get().active_profile;
+    let result = switch_backend(app, name.clone());
+    sync_menu(app);
+
+    let switched = app.state::<ConfigStore>().get().active_profile == name;
+    if switched && previous != name && !matches!(result, Ok(true)) {
+        offer_rollback(app, previous);
+    }
+    result
+}
+
+// The new profile's server didn't come up healthy; offer to go back to the one before
+fn offer_rollback(app: &AppHandle, previous: Option<String>) {
+    let label = previous
+        .clone()
+        .unwrap_or_else(|| i18n::t(app, "tray.profile.default"));
+    let handle = app.clone();
+    app.dialog()
+        .message(i18n::tf(
+            app,
+            "dialog.profile.rollback.message",
+            &[("profile", &label)],
+        ))
+        .title(i18n::t(app, "dialog.profile.title"))
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            i18n::t(app, "dialog.profile.rollback.confirm"),
+            i18n::t(app, "dialog.profile.rollback.keep"),
+        ))
+        .show(move |roll_back| {
+            if !roll_back {
+                return;
+            }
+            std::thread::spawn(move || {
+                if let Err(e) = switch_backend(&handle, previous) {
+                    tracing::warn!(error = %e, "could not switch back to the previous profile");
+                }
+                sync_menu(&handle);
+            });
+        });
+}
+
+fn switch_backend(app: &AppHandle, name: Option<String>) -> Result<bool, String> {
+    app.state::<LockdownState>().ensure_unlocked()?;
+    let _span = tracing::info_span!(
+        "switch_profile",
+        profile = name.as_deref().unwrap_or("default")
+    )
+    .entered();
+
+    // Retire the watchdog first so it can't bring the old server back mid-switch
+    watchdog::stop(app);
+    if let Err(e) = app.state::<ConfigStore>().switch_profile(name) {
+        watchdog::spawn(app.clone());
+        return Err(e);
+    }
+
+    let healthy = crate::restart_api_server(app);
+    watchdog::spawn(app.clone());
+    health_poller::wake(app);
+    healthy
+}
+
+// Check items toggle themselves on click, so re-check the saved profile either way
+fn sync_menu(app: &AppHandle) {
+    let config = app.state::<ConfigStore>().get();
+    let active = config.active_profile.as_deref();
+    tray::set_checked(app, &menu_id(None), active.is_none());
+    for name in config.profiles.keys() {
+        tray::set_checked(app, &menu_id(Some(name)), active == Some(name.as_str()));
+    }
+}
@@ -0,0 +1,60 @@
+// ==============================================================================
+// file_id: SOM-SCR-0089-v1.0.0
+// name: recovery.rs
+// description: AEGIS Desktop - Full stop-and-restart of the backend for wedged states
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, recovery]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::health::{self, HealthReport};
+use crate::lockdown::LockdownState;
+use crate::logs::ServerLogs;
+use crate::safe_mode;
+use crate::server::{start_api_server, stop_api_server, wait_for_port_release, ApiServerState};
+use crate::startup;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+// How long a stopped server's port may stay bound before recovery gives up
+const PORT_RELEASE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Stop the server (graceful, then forced), drop buffered logs, wait until the port is
+// actually free and start a fresh server. Unlike restart_api_server this refuses to
+// start while something still holds the port. Blocks; call it off the event loop.
+pub fn recover(app: &AppHandle) -> Result<HealthReport, String> {
+    app.state::<LockdownState>().ensure_unlocked()?;
+    safe_mode::exit(app);
+    let state = app.state::<ApiServerState>();
+    let config = app.state::<ConfigStore>().api();
+    let client = app.state::<ApiClient>();
+    let logs = app.state::<ServerLogs>();
+
+    let _span = tracing::info_span!("recover", port = config.port).entered();
+
+    let outcome = stop_api_server(&state, config.shutdown_grace());
+    logs.clear();
+    tracing::info!(?outcome, "recovery: server stopped");
+
+    wait_for_port_release(config.port, PORT_RELEASE_TIMEOUT).map_err(|e| e.to_string())?;
+
+    {
+        let mut process_guard = state.lock_process();
+        if process_guard.is_none() {
+            let progress = |progress| startup::report_progress(app, progress);
+            let started =
+                start_api_server(&config, &client, &logs, &progress).map_err(|e| e.to_string())?;
+            state.record_start(&started);
+            *process_guard = Some(started.process);
+        }
+    }
+
+    let report = tauri::async_runtime::block_on(health::check(&client, &config));
+    tracing::info!(healthy = report.is_healthy(), "recovery finished");
+    Ok(report)
+}
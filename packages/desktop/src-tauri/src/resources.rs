@@ -0,0 +1,115 @@
+// ==============================================================================
+// file_id: SOM-SCR-0084-v1.0.0
+// name: resources.rs
+// description: AEGIS Desktop - CPU and memory usage of the API server process tree
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, sysinfo]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::server::ApiServerState;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceUsage {
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub uptime_secs: u64,
+}
+
+// Usage of the managed API server, or None when it isn't running
+pub async fn server_usage(app: AppHandle) -> Result<Option<ResourceUsage>, String> {
+    let pid = {
+        let state = app.state::<ApiServerState>();
+        let mut process_guard = state.lock_process();
+        let Some(process) = process_guard.as_mut() else {
+            return Ok(None);
+        };
+        // An exited child's PID may already belong to something else
+        if !matches!(process.try_wait(), Ok(None)) {
+            return Ok(None);
+        }
+        process.id()
+    };
+
+    // Sampling sleeps between refreshes, so keep it off the async workers
+    tauri::async_runtime::spawn_blocking(move || app.state::<ResourceMonitor>().sample(pid))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// CPU usage is the delta between two refreshes, so the same System is kept across samples
+#[derive(Default)]
+pub struct ResourceMonitor {
+    system: Mutex<System>,
+    last_root: Mutex<Option<Pid>>,
+}
+
+impl ResourceMonitor {
+    // Usage summed over `pid` and its descendants: the child is an npm (or cmd) wrapper and
+    // the actual Node server runs further down the tree. Blocks briefly on the first sample.
+    pub fn sample(&self, pid: u32) -> Option<ResourceUsage> {
+        let root = Pid::from_u32(pid);
+        let mut system = self.system.lock().ok()?;
+
+        let fresh_root = {
+            let mut last_root = self.last_root.lock().ok()?;
+            last_root.replace(root) != Some(root)
+        };
+        refresh(&mut system);
+        if fresh_root {
+            // The first refresh only establishes a baseline for this process
+            std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+            refresh(&mut system);
+        }
+
+        let uptime_secs = system.process(root)?.run_time();
+        let tree = process_tree(&system, root);
+        let (cpu_percent, memory_bytes) = tree
+            .iter()
+            .filter_map(|pid| system.process(*pid))
+            .fold((0.0, 0), |(cpu, memory), process| {
+                (cpu + process.cpu_usage(), memory + process.memory())
+            });
+
+        Some(ResourceUsage {
+            cpu_percent,
+            memory_bytes,
+            uptime_secs,
+        })
+    }
+}
+
+fn refresh(system: &mut System) {
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_cpu().with_memory(),
+    );
+}
+
+fn process_tree(system: &System, root: Pid) -> HashSet<Pid> {
+    let mut tree = HashSet::from([root]);
+    // Keep sweeping until no new descendants turn up; trees are a handful of processes deep
+    loop {
+        let before = tree.len();
+        for (pid, process) in system.processes() {
+            if process
+                .parent()
+                .is_some_and(|parent| tree.contains(&parent))
+            {
+                tree.insert(*pid);
+            }
+        }
+        if tree.len() == before {
+            return tree;
+        }
+    }
+}
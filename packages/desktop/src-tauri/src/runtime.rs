@@ -0,0 +1,111 @@
+// ==============================================================================
+// file_id: SOM-SCR-0081-v1.0.0
+// name: runtime.rs
+// description: AEGIS Desktop - Locating the Node.js runtime used to run the API server
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, node]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+pub const NODE_INSTALL_URL: &str = "https://nodejs.org/en/download";
+
+// Directory under the app resources where packagers drop a Node.js distribution
+const BUNDLED_NODE_DIR: &str = "node";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeSource {
+    Bundled,
+    System,
+}
+
+impl NodeSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Bundled => "bundled",
+            Self::System => "system",
+        }
+    }
+}
+
+// Resolved node and npm executables
+#[derive(Debug, Clone)]
+pub struct NodeRuntime {
+    pub node: PathBuf,
+    pub npm: PathBuf,
+    pub source: NodeSource,
+}
+
+impl NodeRuntime {
+    // Directory holding node, put first on the server's PATH so npm scripts use the same runtime
+    pub fn bin_dir(&self) -> Option<&Path> {
+        self.node.parent()
+    }
+}
+
+// Prefer a runtime shipped in the app resources, falling back to PATH
+pub fn resolve(resource_dir: Option<&Path>) -> Result<NodeRuntime, &'static str> {
+    match resource_dir.and_then(find_bundled_runtime) {
+        Some(runtime) => Ok(runtime),
+        None => find_system_runtime(),
+    }
+}
+
+// Official archives put executables at the root on Windows and under bin/ elsewhere
+fn find_bundled_runtime(resource_dir: &Path) -> Option<NodeRuntime> {
+    let root = resource_dir.join(BUNDLED_NODE_DIR);
+    let bin = if cfg!(target_os = "windows") {
+        root
+    } else {
+        root.join("bin")
+    };
+
+    Some(NodeRuntime {
+        node: executable_in(&bin, "node")?,
+        npm: executable_in(&bin, "npm")?,
+        source: NodeSource::Bundled,
+    })
+}
+
+// Look up node and npm on PATH, naming the first one that is missing
+pub fn find_system_runtime() -> Result<NodeRuntime, &'static str> {
+    let node = find_executable("node").ok_or("node")?;
+    let npm = find_executable("npm").ok_or("npm")?;
+    Ok(NodeRuntime {
+        node,
+        npm,
+        source: NodeSource::System,
+    })
+}
+
+// `which`-style resolution: the first PATH entry holding the program wins
+pub fn find_executable(program: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path).find_map(|dir| executable_in(&dir, program))
+}
+
+// Windows installs npm as npm.cmd, so try each PATHEXT extension
+#[cfg(target_os = "windows")]
+fn executable_in(dir: &Path, program: &str) -> Option<PathBuf> {
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+    extensions
+        .split(';')
+        .filter(|ext| !ext.is_empty())
+        .map(|ext| dir.join(format!("{}{}", program, ext.to_ascii_lowercase())))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(unix)]
+fn executable_in(dir: &Path, program: &str) -> Option<PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let candidate = dir.join(program);
+    let metadata = std::fs::metadata(&candidate).ok()?;
+    (metadata.is_file() && metadata.permissions().mode() & 0o111 != 0).then_some(candidate)
+}
@@ -0,0 +1,78 @@
+// ==============================================================================
+// file_id: SOM-SCR-0104-v1.0.0
+// name: safe_mode.rs
+// description: AEGIS Desktop - Safe mode entered when the backend keeps crashing
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, recovery]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::notifications::notify;
+use crate::windows::{navigate_main_window, reveal_main_window};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+// Sent on entering and leaving safe mode so the dashboard can show or drop its banner
+pub const SAFE_MODE_EVENT: &str = "safe-mode-changed";
+
+// Set once automatic restarts have given up; only a manual start, restart or recover clears it
+#[derive(Default)]
+pub struct SafeModeState {
+    active: AtomicBool,
+    reason: Mutex<Option<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafeModeStatus {
+    pub active: bool,
+    pub reason: Option<String>,
+}
+
+impl SafeModeState {
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    pub fn status(&self) -> SafeModeStatus {
+        SafeModeStatus {
+            active: self.is_active(),
+            reason: self.reason.lock().ok().and_then(|reason| reason.clone()),
+        }
+    }
+}
+
+// Stop retrying and point the user at the recovery panel instead of failing quietly
+pub fn enter(app: &AppHandle, reason: &str) {
+    let state = app.state::<SafeModeState>();
+    if let Ok(mut current) = state.reason.lock() {
+        *current = Some(reason.to_string());
+    }
+    state.active.store(true, Ordering::SeqCst);
+    tracing::error!(reason, "entering safe mode");
+
+    notify(app, &format!("AEGIS is in safe mode: {}", reason));
+    let _ = app.emit(SAFE_MODE_EVENT, state.status());
+    // Headless runs only get the notification; the dashboard shows the safe-mode banner
+    // whenever the window is opened from the tray
+    if reveal_main_window(app) {
+        let _ = navigate_main_window(app, "/troubleshooting");
+    }
+}
+
+// Called before a user-initiated start; a no-op outside safe mode
+pub fn exit(app: &AppHandle) {
+    let state = app.state::<SafeModeState>();
+    if !state.active.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    if let Ok(mut reason) = state.reason.lock() {
+        *reason = None;
+    }
+    tracing::info!("leaving safe mode");
+    let _ = app.emit(SAFE_MODE_EVENT, state.status());
+}
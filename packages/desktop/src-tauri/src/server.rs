@@ -0,0 +1,864 @@
+// ==============================================================================
+// file_id: SOM-SCR-0064-v1.0.0
+// name: server.rs
+// description: AEGIS Desktop - Express API server process management
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::backend_install;
+use crate::client::{ApiClient, API_TOKEN_ENV};
+use crate::config::{
+    ApiServerConfig, BackendTransport, API_PORT_ENV, API_SOCKET_ENV, LOG_LEVEL_ENV,
+    NODE_OPTIONS_ENV,
+};
+use crate::errors::CommandError;
+use crate::health;
+use crate::logs::{LogStream, ServerLogs};
+use crate::runtime::{self, NodeRuntime, NODE_INSTALL_URL};
+use crate::wsl;
+use serde::Serialize;
+use std::fmt;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+// Keeps helper tools like netstat from flashing a console window
+#[cfg(target_os = "windows")]
+pub const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const PORT_RELEASE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+// Event the UI listens for to show the backend boot sequence
+pub const STARTUP_PROGRESS_EVENT: &str = "api-startup-progress";
+
+// State to hold the API server process
+#[derive(Default)]
+pub struct ApiServerState {
+    // Go through lock_process so a poisoned lock is recovered and counted
+    process: Mutex<Option<Child>>,
+    // Timings of the most recent successful start
+    last_start: Mutex<Option<StartupMetrics>>,
+    // When that start became healthy, as epoch milliseconds; cleared when it stops
+    started_at_ms: Mutex<Option<i64>>,
+    // PID of the managed server, 0 once it has stopped; readable while the lock is held
+    pid: AtomicU32,
+    // Set while a background start is under way so callers don't queue up behind it
+    starting: AtomicBool,
+    // Times a thread panicked while holding the process lock
+    poison_recoveries: AtomicU32,
+}
+
+// Whether the server state survived every panic so far, reported by get_state_health
+#[derive(Debug, Clone, Serialize)]
+pub struct StateHealth {
+    pub intact: bool,
+    pub poison_recoveries: u32,
+}
+
+impl ApiServerState {
+    // The slot only ever holds a whole Child or None, so a panic elsewhere can't leave it
+    // half-written; taking the data back out of the poison error is safe.
+    pub fn lock_process(&self) -> MutexGuard<'_, Option<Child>> {
+        self.process
+            .lock()
+            .unwrap_or_else(|poisoned| self.recover(poisoned.into_inner()))
+    }
+
+    // None while another thread holds the lock
+    pub fn try_lock_process(&self) -> Option<MutexGuard<'_, Option<Child>>> {
+        match self.process.try_lock() {
+            Ok(process_guard) => Some(process_guard),
+            Err(TryLockError::WouldBlock) => None,
+            Err(TryLockError::Poisoned(poisoned)) => Some(self.recover(poisoned.into_inner())),
+        }
+    }
+
+    fn recover<'a>(
+        &self,
+        process_guard: MutexGuard<'a, Option<Child>>,
+    ) -> MutexGuard<'a, Option<Child>> {
+        let count = self.poison_recoveries.fetch_add(1, Ordering::SeqCst) + 1;
+        tracing::error!(
+            count,
+            tracked = process_guard.is_some(),
+            "api server process lock was poisoned by a panicked thread, recovering"
+        );
+        // Clear it so the next lock doesn't report the same panic again
+        self.process.clear_poison();
+        process_guard
+    }
+
+    pub fn health(&self) -> StateHealth {
+        let poison_recoveries = self.poison_recoveries.load(Ordering::SeqCst);
+        StateHealth {
+            intact: poison_recoveries == 0,
+            poison_recoveries,
+        }
+    }
+
+    // Claim the background start; false when another one is already running
+    pub fn begin_start(&self) -> bool {
+        self.starting
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    pub fn finish_start(&self) {
+        self.starting.store(false, Ordering::SeqCst);
+    }
+
+    // Called by every path that installs a started server, watchdog restarts included
+    pub fn record_start(&self, started: &StartedServer) {
+        if let Ok(mut last_start) = self.last_start.lock() {
+            *last_start = Some(started.metrics());
+        }
+        if let Ok(mut started_at_ms) = self.started_at_ms.lock() {
+            *started_at_ms = Some(chrono::Utc::now().timestamp_millis());
+        }
+        self.pid.store(started.process.id(), Ordering::SeqCst);
+    }
+
+    // Called whenever the child leaves the slot, whether stopped or found exited
+    pub fn record_stop(&self) {
+        self.pid.store(0, Ordering::SeqCst);
+        if let Ok(mut started_at_ms) = self.started_at_ms.lock() {
+            *started_at_ms = None;
+        }
+    }
+
+    // The managed server's PID, without waiting on a start or stop that holds the lock
+    pub fn pid(&self) -> Option<u32> {
+        match self.try_lock_process() {
+            Some(process_guard) => process_guard.as_ref().map(Child::id),
+            None => Some(self.pid.load(Ordering::SeqCst)).filter(|&pid| pid != 0),
+        }
+    }
+
+    // When the managed server became healthy; None unless one is running. Measured by the
+    // app, so it holds even when the backend's /api/status can't be reached, and never
+    // waits on the process lock.
+    pub fn started_at_ms(&self) -> Option<i64> {
+        self.started_at_ms
+            .lock()
+            .ok()
+            .and_then(|started_at_ms| *started_at_ms)
+    }
+
+    pub fn last_start(&self) -> Option<StartupMetrics> {
+        self.last_start
+            .lock()
+            .ok()
+            .and_then(|last_start| *last_start)
+    }
+}
+
+// A server that spawned and answered its health check
+pub struct StartedServer {
+    pub process: Child,
+    pub attempts: u32,
+    // From spawning the process that became healthy to its first healthy response
+    pub spawn_to_ready: Duration,
+}
+
+impl StartedServer {
+    pub fn metrics(&self) -> StartupMetrics {
+        StartupMetrics {
+            spawn_to_ready_ms: self.spawn_to_ready.as_millis() as u64,
+            attempts: self.attempts,
+        }
+    }
+}
+
+// How long a start took, for comparing cold starts across machines and releases
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct StartupMetrics {
+    pub spawn_to_ready_ms: u64,
+    pub attempts: u32,
+}
+
+// One step of a start, in order: spawning and waiting repeat per attempt, then ready or failed
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "phase", rename_all = "lowercase")]
+pub enum StartupProgress {
+    Spawning {
+        attempt: u32,
+    },
+    Waiting {
+        attempt: u32,
+    },
+    Ready {
+        attempts: u32,
+        spawn_to_ready_ms: u64,
+    },
+    Failed {
+        error: CommandError,
+    },
+}
+
+// Sent when a restart begins and again when it has finished
+pub const RELOAD_EVENT: &str = "backend-reloading";
+
+// `reloading: true` while the old server is down; `healthy` and `error` describe the result
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendReload {
+    pub reloading: bool,
+    pub healthy: Option<bool>,
+    pub error: Option<String>,
+}
+
+impl BackendReload {
+    pub fn started() -> Self {
+        Self {
+            reloading: true,
+            healthy: None,
+            error: None,
+        }
+    }
+
+    pub fn finished(result: &Result<bool, String>) -> Self {
+        Self {
+            reloading: false,
+            healthy: Some(matches!(result, Ok(true))),
+            error: result.as_ref().err().cloned(),
+        }
+    }
+}
+
+// What start_api did. It returns before the server is ready; readiness arrives as
+// STARTUP_PROGRESS_EVENT.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartApiStatus {
+    // Started, awaiting readiness, or joined a start that was already under way
+    Starting,
+    AlreadyRunning,
+}
+
+// Why the API server could not be started
+#[derive(Debug)]
+pub enum StartError {
+    PortInUse {
+        port: u16,
+        pid: Option<u32>,
+    },
+    // Another server answers on api.socket_path
+    SocketInUse(PathBuf),
+    InvalidDashboardPath(PathBuf),
+    // package.json has no script for the configured server mode
+    MissingScript {
+        script: &'static str,
+        package_json: PathBuf,
+    },
+    // No dashboard_path configured and the working directory is too shallow to hold
+    // the monorepo layout; None when current_dir itself couldn't be read
+    ProjectRootNotFound {
+        current_dir: Option<PathBuf>,
+    },
+    // The dashboard has no populated node_modules
+    DependenciesMissing(PathBuf),
+    // node or npm is not installed / not on PATH
+    RuntimeNotFound(&'static str),
+    // backend_transport is wsl but WSL or the configured distro is missing
+    WslUnavailable(String),
+    // The executable exists but could not be launched
+    SpawnFailed(String),
+    NotReady {
+        attempts: u32,
+    },
+}
+
+impl fmt::Display for StartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartError::PortInUse {
+                port,
+                pid: Some(pid),
+            } => write!(f, "Port {} is already in use by process {}", port, pid),
+            StartError::PortInUse { port, pid: None } => {
+                write!(f, "Port {} is already in use by another process", port)
+            }
+            StartError::SocketInUse(path) => write!(
+                f,
+                "Socket {} is already in use by another server",
+                path.display()
+            ),
+            StartError::InvalidDashboardPath(path) => write!(
+                f,
+                "Dashboard path {} does not contain a package.json",
+                path.display()
+            ),
+            StartError::MissingScript {
+                script,
+                package_json,
+            } => write!(
+                f,
+                "{} has no \"{}\" script; add it or change api.server_mode",
+                package_json.display(),
+                script
+            ),
+            StartError::ProjectRootNotFound {
+                current_dir: Some(dir),
+            } => write!(
+                f,
+                "Could not find the AEGIS project root two levels above {}. Run AEGIS from \
+                 packages/desktop or set api.dashboard_path in aegis.toml",
+                dir.display()
+            ),
+            StartError::ProjectRootNotFound { current_dir: None } => write!(
+                f,
+                "Could not read the working directory to find the AEGIS project root. \
+                 Set api.dashboard_path in aegis.toml"
+            ),
+            StartError::DependenciesMissing(path) => write!(
+                f,
+                "Dashboard dependencies are not installed; run `npm install` in {}",
+                path.display()
+            ),
+            StartError::RuntimeNotFound(program) => write!(
+                f,
+                "{} was not found on PATH. Node.js is required: {}",
+                program, NODE_INSTALL_URL
+            ),
+            StartError::WslUnavailable(reason) => f.write_str(reason),
+            StartError::SpawnFailed(reason) => {
+                write!(f, "Failed to spawn API server: {}", reason)
+            }
+            StartError::NotReady { attempts } => write!(
+                f,
+                "API server did not become healthy after {} attempt(s)",
+                attempts
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StartError {}
+
+// Start the Express API server, retrying with exponential backoff until it is healthy.
+// Blocks until it is ready or has failed, reporting each step through `progress`.
+pub fn start_api_server(
+    config: &ApiServerConfig,
+    client: &ApiClient,
+    logs: &ServerLogs,
+    progress: &dyn Fn(StartupProgress),
+) -> Result<StartedServer, StartError> {
+    let result = start_with_retries(config, client, logs, progress);
+    progress(match &result {
+        Ok(started) => StartupProgress::Ready {
+            attempts: started.attempts,
+            spawn_to_ready_ms: started.metrics().spawn_to_ready_ms,
+        },
+        Err(e) => StartupProgress::Failed { error: e.into() },
+    });
+    result
+}
+
+fn start_with_retries(
+    config: &ApiServerConfig,
+    client: &ApiClient,
+    logs: &ServerLogs,
+    progress: &dyn Fn(StartupProgress),
+) -> Result<StartedServer, StartError> {
+    let _span = tracing::info_span!("start_api_server", port = config.port).entered();
+    match config.unix_socket() {
+        #[cfg(unix)]
+        Some(socket) => prepare_socket(socket)?,
+        _ => ensure_port_available(config.port)?,
+    }
+
+    let dashboard_path = resolve_dashboard_path(config)?;
+    let script = config.server_mode().script();
+    let launcher = match config.backend_transport {
+        BackendTransport::Native => {
+            backend_install::inspect(&dashboard_path).require(script)?;
+            let node_runtime = runtime::resolve(config.resource_dir.as_deref())
+                .map_err(StartError::RuntimeNotFound)?;
+            tracing::debug!(
+                source = ?node_runtime.source,
+                node = %node_runtime.node.display(),
+                npm = %node_runtime.npm.display(),
+                "using node runtime"
+            );
+            Launcher::Native(node_runtime)
+        }
+        // The dashboard may be a path inside WSL, which can't be inspected from here
+        BackendTransport::Wsl => {
+            let distro = wsl::check_distro(config.wsl_distro.as_deref())
+                .map_err(StartError::WslUnavailable)?;
+            tracing::debug!(distro, "running api server in wsl");
+            Launcher::Wsl(distro)
+        }
+    };
+
+    let attempts = config.start_attempts.max(1);
+    let mut process: Option<Child> = None;
+    let mut spawned_at = Instant::now();
+    let mut last_error = None;
+
+    for attempt in 1..=attempts {
+        // Only respawn when there is no live process; a slow boot just gets more time
+        let mut child = match process.take() {
+            Some(child) => child,
+            None => {
+                progress(StartupProgress::Spawning { attempt });
+                match spawn_server(
+                    config,
+                    &launcher,
+                    &dashboard_path,
+                    script,
+                    &client.token(),
+                    logs,
+                ) {
+                    Ok(child) => {
+                        tracing::info!(pid = child.id(), attempt, script, "api server spawned");
+                        spawned_at = Instant::now();
+                        child
+                    }
+                    Err(e) => {
+                        tracing::warn!(attempt, error = %e, "api server spawn failed");
+                        last_error = Some(e);
+                        std::thread::sleep(backoff_delay(config, attempt));
+                        continue;
+                    }
+                }
+            }
+        };
+
+        progress(StartupProgress::Waiting { attempt });
+        std::thread::sleep(backoff_delay(config, attempt));
+
+        if tauri::async_runtime::block_on(health::probe(client, config)) {
+            // Includes the backoff sleep, so it is as precise as the probe schedule
+            let spawn_to_ready = spawned_at.elapsed();
+            tracing::info!(
+                pid = child.id(),
+                attempts = attempt,
+                spawn_to_ready_ms = spawn_to_ready.as_millis() as u64,
+                "api server healthy"
+            );
+            return Ok(StartedServer {
+                process: child,
+                attempts: attempt,
+                spawn_to_ready,
+            });
+        }
+
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                tracing::warn!(attempt, %status, "api server exited before becoming healthy");
+                last_error = Some(StartError::SpawnFailed(format!(
+                    "server exited with {}",
+                    status
+                )));
+            }
+            _ => {
+                tracing::debug!(attempt, "api server not healthy yet");
+                process = Some(child);
+            }
+        }
+    }
+
+    // Never became healthy; don't leave an unmanaged process behind
+    if let Some(mut child) = process {
+        tracing::error!(
+            pid = child.id(),
+            attempts,
+            "api server never became healthy; killing it"
+        );
+        force_kill(&mut child);
+        let _ = child.wait();
+        return Err(StartError::NotReady { attempts });
+    }
+
+    Err(last_error.unwrap_or(StartError::NotReady { attempts }))
+}
+
+// Where the backend is expected to live, for "can't find the server" reports
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardLocation {
+    pub path: PathBuf,
+    pub exists: bool,
+    // Set by api.dashboard_path rather than derived from the working directory
+    pub configured: bool,
+}
+
+pub fn locate_dashboard(config: &ApiServerConfig) -> Result<DashboardLocation, StartError> {
+    let path = resolve_dashboard_path(config)?;
+    Ok(DashboardLocation {
+        exists: path.is_dir(),
+        configured: config.dashboard_path.is_some(),
+        path,
+    })
+}
+
+// Configured dashboard directory, or packages/dashboard relative to the monorepo layout.
+// Whether it holds an installed package is up to backend_install.
+pub fn resolve_dashboard_path(config: &ApiServerConfig) -> Result<PathBuf, StartError> {
+    if let Some(path) = &config.dashboard_path {
+        return Ok(path.clone());
+    }
+
+    // Assumes the working directory is packages/desktop, two levels below the repo root
+    let current_dir = match std::env::current_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            tracing::warn!(error = %e, "could not read current_dir");
+            return Err(StartError::ProjectRootNotFound { current_dir: None });
+        }
+    };
+    let Some(root) = current_dir.parent().and_then(Path::parent) else {
+        tracing::warn!(current_dir = %current_dir.display(), "current_dir has no project root above it");
+        return Err(StartError::ProjectRootNotFound {
+            current_dir: Some(current_dir),
+        });
+    };
+
+    let dashboard_path = root.join("packages").join("dashboard");
+    if !dashboard_path.join("package.json").is_file() {
+        tracing::warn!(
+            current_dir = %current_dir.display(),
+            dashboard = %dashboard_path.display(),
+            "no dashboard relative to current_dir"
+        );
+    }
+    Ok(dashboard_path)
+}
+
+// How the server process is started
+enum Launcher {
+    // npm from the resolved Node.js runtime
+    Native(NodeRuntime),
+    // npm inside this WSL distro
+    Wsl(String),
+}
+
+impl Launcher {
+    // The executable actually spawned, for errors
+    fn program(&self) -> String {
+        match self {
+            Launcher::Native(node_runtime) => node_runtime.npm.display().to_string(),
+            Launcher::Wsl(_) => "wsl.exe".into(),
+        }
+    }
+}
+
+fn spawn_server(
+    config: &ApiServerConfig,
+    launcher: &Launcher,
+    dashboard_path: &Path,
+    script: &str,
+    token: &str,
+    logs: &ServerLogs,
+) -> Result<Child, StartError> {
+    let mut command = match launcher {
+        Launcher::Native(node_runtime) => {
+            // On Windows npm resolves to npm.cmd, which std runs through cmd.exe
+            let mut command = Command::new(&node_runtime.npm);
+            if let Some(bin_dir) = node_runtime.bin_dir() {
+                let inherited = std::env::var_os("PATH").unwrap_or_default();
+                let path = std::env::join_paths(
+                    std::iter::once(bin_dir.to_path_buf()).chain(std::env::split_paths(&inherited)),
+                )
+                .map_err(|e| StartError::SpawnFailed(e.to_string()))?;
+                command.env("PATH", path);
+            }
+            command.args(["run", script]).current_dir(dashboard_path);
+            command
+        }
+        Launcher::Wsl(distro) => {
+            let mut command = wsl::npm_command(Some(distro), dashboard_path, script);
+            // WSL only passes on the Windows variables WSLENV names
+            let names = config.server_env.keys().map(String::as_str).chain([
+                NODE_OPTIONS_ENV,
+                LOG_LEVEL_ENV,
+                API_PORT_ENV,
+                API_TOKEN_ENV,
+            ]);
+            command.env("WSLENV", wsl::wslenv(names));
+            command
+        }
+    };
+
+    if let Some(socket) = config.unix_socket() {
+        command.env(API_SOCKET_ENV, socket);
+    }
+
+    let mut child = command
+        .envs(&config.server_env)
+        .envs(
+            config
+                .node_options()
+                .map(|options| (NODE_OPTIONS_ENV, options)),
+        )
+        // After server_env so a level chosen at runtime wins
+        .envs(
+            config
+                .log_level
+                .as_ref()
+                .map(|level| (LOG_LEVEL_ENV, level)),
+        )
+        .env(API_PORT_ENV, config.port.to_string())
+        .env(API_TOKEN_ENV, token)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            StartError::SpawnFailed(format!(
+                "{} was found but failed to run: {}",
+                launcher.program(),
+                e
+            ))
+        })?;
+
+    if let Some(stdout) = child.stdout.take() {
+        logs.capture(LogStream::Stdout, stdout);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        logs.capture(LogStream::Stderr, stderr);
+    }
+
+    Ok(child)
+}
+
+// 250ms, 500ms, 1s, 2s, ... from the configured base
+fn backoff_delay(config: &ApiServerConfig, attempt: u32) -> Duration {
+    let factor = 1u64 << (attempt.saturating_sub(1)).min(16);
+    Duration::from_millis(config.start_retry_base_ms.saturating_mul(factor))
+}
+
+// How the API server ended up stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownOutcome {
+    Graceful,
+    Forced,
+    NotRunning,
+}
+
+// Stop the API server process: ask it to exit, then kill it once the grace period runs out
+pub fn stop_api_server(state: &ApiServerState, grace: Duration) -> ShutdownOutcome {
+    // Take the child out first so the lock isn't held while we wait on it
+    let process = state.lock_process().take();
+    state.record_stop();
+
+    let Some(mut process) = process else {
+        tracing::debug!("stop requested but api server is not running");
+        return ShutdownOutcome::NotRunning;
+    };
+    let pid = process.id();
+    tracing::info!(
+        pid,
+        grace_ms = grace.as_millis() as u64,
+        "stopping api server"
+    );
+
+    if request_termination(&process) {
+        let deadline = Instant::now() + grace;
+        while Instant::now() < deadline {
+            match process.try_wait() {
+                Ok(Some(status)) => {
+                    tracing::info!(pid, %status, "api server exited gracefully");
+                    return ShutdownOutcome::Graceful;
+                }
+                Ok(None) => std::thread::sleep(SHUTDOWN_POLL_INTERVAL),
+                Err(_) => break,
+            }
+        }
+    }
+
+    force_kill(&mut process);
+    let _ = process.wait();
+    tracing::warn!(pid, "api server killed after grace period");
+    ShutdownOutcome::Forced
+}
+
+// Send SIGTERM so Node can flush state and close its sockets
+#[cfg(unix)]
+fn request_termination(process: &Child) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(process.id()) else {
+        return false;
+    };
+    // SAFETY: kill(2) has no memory-safety preconditions; the PID belongs to our own child
+    unsafe { libc::kill(pid, libc::SIGTERM) == 0 }
+}
+
+// taskkill without /F asks the process to close instead of terminating it.
+// /T matters on Windows: the child is a `cmd` wrapper and node runs as its grandchild.
+#[cfg(target_os = "windows")]
+fn request_termination(process: &Child) -> bool {
+    taskkill_tree(process.id(), false)
+}
+
+#[cfg(unix)]
+fn force_kill(process: &mut Child) {
+    let _ = process.kill();
+}
+
+#[cfg(target_os = "windows")]
+fn force_kill(process: &mut Child) {
+    if !taskkill_tree(process.id(), true) {
+        let _ = process.kill();
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn taskkill_tree(pid: u32, force: bool) -> bool {
+    let mut command = Command::new("taskkill");
+    command.args(["/T", "/PID", &pid.to_string()]);
+    if force {
+        command.arg("/F");
+    }
+    command
+        .creation_flags(CREATE_NO_WINDOW)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+// Pre-flight check so a stale server on the port is reported instead of spawning blindly
+pub fn ensure_port_available(port: u16) -> Result<(), StartError> {
+    match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => {
+            drop(listener);
+            Ok(())
+        }
+        Err(_) => Err(StartError::PortInUse {
+            port,
+            pid: find_listening_pid(port),
+        }),
+    }
+}
+
+// A killed server leaves its socket file behind, which would stop the next one from
+// binding. Remove it unless a live server still answers there.
+#[cfg(unix)]
+fn prepare_socket(socket: &Path) -> Result<(), StartError> {
+    if !socket.exists() {
+        return Ok(());
+    }
+    if std::os::unix::net::UnixStream::connect(socket).is_ok() {
+        return Err(StartError::SocketInUse(socket.to_path_buf()));
+    }
+    tracing::debug!(socket = %socket.display(), "removing stale api socket");
+    std::fs::remove_file(socket).map_err(|e| {
+        StartError::SpawnFailed(format!(
+            "could not remove the stale socket {}: {}",
+            socket.display(),
+            e
+        ))
+    })
+}
+
+// Poll until nothing is bound to the port, e.g. while a killed server's sockets close
+pub fn wait_for_port_release(port: u16, timeout: Duration) -> Result<(), StartError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match ensure_port_available(port) {
+            Err(e) if Instant::now() < deadline => {
+                tracing::debug!(port, error = %e, "waiting for port to be released");
+                std::thread::sleep(PORT_RELEASE_POLL_INTERVAL);
+            }
+            result => return result,
+        }
+    }
+}
+
+// Look up the PID listening on a TCP port using the platform's own tools
+#[cfg(target_os = "windows")]
+pub fn find_listening_pid(port: u16) -> Option<u32> {
+    let output = Command::new("netstat")
+        .args(["-ano", "-p", "TCP"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .ok()?;
+    let suffix = format!(":{}", port);
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| {
+            // e.g. "  TCP    0.0.0.0:4243    0.0.0.0:0    LISTENING    1234"
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.as_slice() {
+                [_, local, _, state, pid] if local.ends_with(&suffix) && *state == "LISTENING" => {
+                    pid.parse().ok()
+                }
+                _ => None,
+            }
+        })
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn find_listening_pid(port: u16) -> Option<u32> {
+    let output = Command::new("lsof")
+        .args(["-nP", &format!("-iTCP:{}", port), "-sTCP:LISTEN", "-t"])
+        .output()
+        .ok()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.trim().parse().ok())
+}
+
+#[cfg(all(test, target_os = "windows"))]
+mod tests {
+    use super::*;
+    use sysinfo::{Pid, ProcessesToUpdate, System};
+
+    // cmd starts its child asynchronously, so poll until it shows up
+    fn find_child(system: &mut System, parent: u32, name: &str) -> Option<u32> {
+        for _ in 0..50 {
+            system.refresh_processes(ProcessesToUpdate::All, true);
+            let child = system.processes().values().find(|process| {
+                process.parent() == Some(Pid::from_u32(parent))
+                    && process.name().eq_ignore_ascii_case(name)
+            });
+            if let Some(child) = child {
+                return Some(child.pid().as_u32());
+            }
+            std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+        }
+        None
+    }
+
+    fn alive(system: &mut System, pid: u32) -> bool {
+        let pid = Pid::from_u32(pid);
+        system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        system.process(pid).is_some()
+    }
+
+    #[test]
+    fn force_kill_ends_the_whole_tree() {
+        // Shaped like `cmd /C npm run dev:server`: the wrapper waits on a long-running child
+        let mut wrapper = Command::new("cmd")
+            .args(["/C", "ping", "-n", "60", "127.0.0.1"])
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("could not spawn cmd");
+        let wrapper_pid = wrapper.id();
+        let mut system = System::new();
+        let child_pid =
+            find_child(&mut system, wrapper_pid, "ping.exe").expect("cmd did not start ping");
+
+        force_kill(&mut wrapper);
+        let _ = wrapper.wait();
+
+        // taskkill returns once the tree is signalled, not once it is gone
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while alive(&mut system, child_pid) && Instant::now() < deadline {
+            std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+        }
+        assert!(!alive(&mut system, wrapper_pid), "cmd wrapper survived");
+        assert!(!alive(&mut system, child_pid), "grandchild survived");
+    }
+}
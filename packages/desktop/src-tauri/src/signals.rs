@@ -0,0 +1,70 @@
+// ==============================================================================
+// file_id: SOM-SCR-0101-v1.0.0
+// name: signals.rs
+// description: AEGIS Desktop - Clean shutdown on Ctrl-C and termination signals
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, signals]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use tauri::AppHandle;
+
+// Exit code for a second Ctrl-C: 128 + SIGINT
+const FORCED_EXIT_CODE: i32 = 130;
+
+// Run the normal shutdown when the process is interrupted from a terminal or told to
+// terminate, so the Node server isn't orphaned. Spawned from setup, after every piece of
+// state shutdown touches is managed.
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let signal = wait_for_signal().await;
+        tracing::info!(signal, "shutdown signal received");
+
+        // Stopping waits out the shutdown grace period, so keep it off the async runtime
+        std::thread::spawn(move || crate::shutdown(&app));
+
+        // A second Ctrl-C means "now": skip whatever the graceful stop is waiting on
+        if tokio::signal::ctrl_c().await.is_ok() {
+            tracing::warn!("second interrupt, exiting immediately");
+            std::process::exit(FORCED_EXIT_CODE);
+        }
+    });
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() -> &'static str {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = match signal(SignalKind::terminate()) {
+        Ok(terminate) => terminate,
+        Err(e) => {
+            tracing::warn!(error = %e, "could not listen for SIGTERM");
+            let _ = tokio::signal::ctrl_c().await;
+            return "SIGINT";
+        }
+    };
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => "SIGINT",
+        _ = terminate.recv() => "SIGTERM",
+    }
+}
+
+// Ctrl-C, or the console window being closed
+#[cfg(windows)]
+async fn wait_for_signal() -> &'static str {
+    let mut close = match tokio::signal::windows::ctrl_close() {
+        Ok(close) => close,
+        Err(e) => {
+            tracing::warn!(error = %e, "could not listen for console close");
+            let _ = tokio::signal::ctrl_c().await;
+            return "CTRL_C";
+        }
+    };
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => "CTRL_C",
+        _ = close.recv() => "CTRL_CLOSE",
+    }
+}
@@ -0,0 +1,129 @@
+// ==============================================================================
+// file_id: SOM-SCR-0106-v1.0.0
+// name: snapshot.rs
+// description: AEGIS Desktop - Export and import of app state for support and tests
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, config, diagnostics]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::{Config, ConfigStore};
+use crate::health_poller::{HealthPollerState, HealthSample};
+use crate::lockdown::{self, LockdownState};
+use crate::safe_mode::{self, SafeModeState, SafeModeStatus};
+use crate::server::ApiServerState;
+use crate::watchdog::{PauseStatus, WatchdogState, DEFAULT_PAUSE};
+use crate::windows;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+// Bumped when a field changes meaning, so old snapshots are refused instead of misread
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+// Everything that shapes how the app behaves, minus live handles: no server process and
+// no API token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppSnapshot {
+    pub format: u32,
+    pub app_version: String,
+    pub exported_at: String,
+    pub config: Config,
+    pub health_history: Vec<HealthSample>,
+    pub watchdog_restarts: u32,
+    pub watchdog: PauseStatus,
+    pub safe_mode: SafeModeStatus,
+    pub lockdown: bool,
+}
+
+pub fn export(app: &AppHandle) -> AppSnapshot {
+    let watchdog = app.state::<WatchdogState>();
+    AppSnapshot {
+        format: SNAPSHOT_FORMAT,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: chrono::Local::now().to_rfc3339(),
+        config: app.state::<ConfigStore>().get(),
+        health_history: app.state::<HealthPollerState>().history(),
+        watchdog_restarts: watchdog.restart_count.load(Ordering::SeqCst),
+        watchdog: watchdog.pause_status(),
+        safe_mode: app.state::<SafeModeState>().status(),
+        lockdown: app.state::<LockdownState>().is_active(),
+    }
+}
+
+// Save the snapshot's config and put the in-memory state where it was. The token is
+// always replaced, and a running server is restarted so it expects the new one.
+// Returns the config fields that need an app restart. Blocks; call it off the event loop.
+pub fn import(app: &AppHandle, snapshot: AppSnapshot) -> Result<Vec<&'static str>, String> {
+    if snapshot.format != SNAPSHOT_FORMAT {
+        return Err(format!(
+            "Snapshot format {} is not supported (expected {})",
+            snapshot.format, SNAPSHOT_FORMAT
+        ));
+    }
+
+    let config = app.state::<ConfigStore>();
+    let before = config.preferences();
+    let pending = config.update(|saved| *saved = snapshot.config)?;
+    app.state::<HealthPollerState>()
+        .restore(snapshot.health_history);
+    crate::apply_replaced_preferences(app, &before);
+
+    let watchdog = app.state::<WatchdogState>();
+    watchdog
+        .restart_count
+        .store(snapshot.watchdog_restarts, Ordering::SeqCst);
+    if snapshot.watchdog.paused {
+        watchdog.pause(
+            snapshot
+                .watchdog
+                .resumes_in_secs
+                .map_or(DEFAULT_PAUSE, Duration::from_secs),
+        );
+    } else {
+        watchdog.resume();
+    }
+
+    if snapshot.lockdown {
+        // Stops the server and wipes the token; unlocking issues a new one
+        lockdown::engage(app);
+    } else {
+        if app.state::<LockdownState>().is_active() {
+            lockdown::release(app);
+        } else {
+            app.state::<ApiClient>().rotate_token();
+        }
+        // Route windows still hold the old token
+        windows::close_route_windows(app);
+        let running = app.state::<ApiServerState>().lock_process().is_some();
+        if running {
+            if let Err(e) = crate::restart_api_server(app) {
+                tracing::warn!(error = %e, "could not restart api server after import");
+            }
+        }
+    }
+
+    // Last, because a restart leaves safe mode
+    if snapshot.safe_mode.active {
+        let reason = snapshot.safe_mode.reason.as_deref();
+        safe_mode::enter(
+            app,
+            reason.unwrap_or("it was active in the imported snapshot"),
+        );
+    } else {
+        safe_mode::exit(app);
+    }
+
+    tracing::info!(
+        from_version = %snapshot.app_version,
+        exported_at = %snapshot.exported_at,
+        ?pending,
+        "state imported"
+    );
+    Ok(pending)
+}
@@ -0,0 +1,286 @@
+// ==============================================================================
+// file_id: SOM-SCR-0095-v1.0.0
+// name: stale_server.rs
+// description: AEGIS Desktop - Finds and stops leftover AEGIS server processes
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, recovery]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::{ConfigStore, API_PORT_ENV};
+use crate::server::{
+    ensure_port_available, find_listening_pid, wait_for_port_release, ApiServerState,
+};
+use serde::Serialize;
+use std::ffi::OsStr;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, Signal, System, UpdateKind};
+use tauri::{AppHandle, Manager};
+
+// How long a terminated server gets to close its socket before it is killed outright
+const TERMINATE_GRACE: Duration = Duration::from_secs(5);
+
+// How often kill_orphaned_backends checks whether terminated orphans have exited
+const ORPHAN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum FreePortOutcome {
+    // Nothing was listening on the port
+    AlreadyFree,
+    // A leftover AEGIS server held the port and was stopped
+    Freed { pid: u32, name: String },
+    // Another program holds the port; it was left alone so the user can decide
+    NotAegis { pid: u32, name: String },
+    // The port is taken but its owner could not be identified
+    UnknownOwner,
+}
+
+// A Node process that looks like an AEGIS server but isn't the one this app manages
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanedBackend {
+    pub pid: u32,
+    pub name: String,
+    // From the AEGIS_API_PORT it was spawned with; None for servers started by hand
+    pub port: Option<u16>,
+    pub command: String,
+}
+
+// Whether a candidate port is free and, when it isn't, who holds it
+#[derive(Debug, Clone, Serialize)]
+pub struct PortTest {
+    pub port: u16,
+    pub free: bool,
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+}
+
+// Bind the port and drop it right away. Takes a u32 so out-of-range input from the UI
+// is reported rather than failing to deserialize.
+pub fn test_port(port: u32) -> Result<PortTest, String> {
+    let port = u16::try_from(port)
+        .ok()
+        .filter(|port| *port >= 1024)
+        .ok_or_else(|| format!("Port must be between 1024 and 65535, got {}", port))?;
+    if ensure_port_available(port).is_ok() {
+        return Ok(PortTest {
+            port,
+            free: true,
+            pid: None,
+            process_name: None,
+        });
+    }
+
+    let pid = find_listening_pid(port);
+    let process_name = pid.and_then(|pid| {
+        let pid = Pid::from_u32(pid);
+        let mut system = System::new();
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+        system
+            .process(pid)
+            .map(|process| process.name().to_string_lossy().into_owned())
+    });
+    Ok(PortTest {
+        port,
+        free: false,
+        pid,
+        process_name,
+    })
+}
+
+// Find whoever listens on the configured port and stop it, but only if it is a stale
+// AEGIS server. The server this app is currently managing is never touched.
+pub fn free_api_port(app: &AppHandle) -> Result<FreePortOutcome, String> {
+    let port = app.state::<ConfigStore>().api().port;
+    if ensure_port_available(port).is_ok() {
+        return Ok(FreePortOutcome::AlreadyFree);
+    }
+    let Some(pid) = find_listening_pid(port) else {
+        return Ok(FreePortOutcome::UnknownOwner);
+    };
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always)
+            .with_environ(UpdateKind::Always),
+    );
+    let Some(process) = system.process(Pid::from_u32(pid)) else {
+        return Ok(FreePortOutcome::UnknownOwner);
+    };
+    let name = process.name().to_string_lossy().into_owned();
+
+    let managed_pid = app
+        .state::<ApiServerState>()
+        .lock_process()
+        .as_ref()
+        .map(|child| child.id());
+    if managed_pid.is_some_and(|managed| is_descendant(&system, pid, managed)) {
+        return Err(format!(
+            "Port {} is held by the API server AEGIS is running; restart it instead",
+            port
+        ));
+    }
+
+    if !looks_like_aegis(process) {
+        tracing::info!(port, pid, %name, "port held by another program; not stopping it");
+        return Ok(FreePortOutcome::NotAegis { pid, name });
+    }
+
+    tracing::warn!(port, pid, %name, "stopping stale aegis server");
+    // SIGTERM isn't available on Windows; sysinfo reports None and we go straight to kill
+    if process.kill_with(Signal::Term) != Some(true)
+        || wait_for_port_release(port, TERMINATE_GRACE).is_err()
+    {
+        process.kill();
+    }
+    wait_for_port_release(port, TERMINATE_GRACE).map_err(|e| e.to_string())?;
+
+    Ok(FreePortOutcome::Freed { pid, name })
+}
+
+// Every AEGIS-looking Node process outside the managed server's tree. The npm wrapper and
+// the node it runs are listed separately, so both get stopped.
+pub fn find_orphaned_backends(app: &AppHandle) -> Vec<OrphanedBackend> {
+    let system = scan_processes();
+    orphans(app, &system)
+        .into_iter()
+        .map(|process| OrphanedBackend {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string_lossy().into_owned(),
+            port: spawned_port(process),
+            command: process
+                .cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+        .collect()
+}
+
+// Stop the orphans found by a fresh scan: SIGTERM first where there is one, then a kill
+// for any still running after TERMINATE_GRACE. Returns the PIDs that were stopped.
+pub fn kill_orphaned_backends(app: &AppHandle) -> Vec<u32> {
+    let mut system = scan_processes();
+    let pids: Vec<Pid> = orphans(app, &system)
+        .into_iter()
+        .map(|process| {
+            tracing::warn!(pid = %process.pid(), "stopping orphaned aegis server");
+            process.kill_with(Signal::Term);
+            process.pid()
+        })
+        .collect();
+    if pids.is_empty() {
+        return Vec::new();
+    }
+
+    let deadline = Instant::now() + TERMINATE_GRACE;
+    loop {
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&pids),
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+        let running: Vec<&Process> = pids.iter().filter_map(|pid| system.process(*pid)).collect();
+        if running.is_empty() {
+            break;
+        }
+        if Instant::now() >= deadline {
+            for process in running {
+                process.kill();
+            }
+            break;
+        }
+        std::thread::sleep(ORPHAN_POLL_INTERVAL);
+    }
+    pids.into_iter().map(Pid::as_u32).collect()
+}
+
+fn scan_processes() -> System {
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always)
+            .with_environ(UpdateKind::Always),
+    );
+    system
+}
+
+fn orphans<'a>(app: &AppHandle, system: &'a System) -> Vec<&'a Process> {
+    let managed_pid = app
+        .state::<ApiServerState>()
+        .lock_process()
+        .as_ref()
+        .map(|child| child.id());
+    let own_pid = std::process::id();
+    system
+        .processes()
+        .values()
+        .filter(|process| looks_like_aegis(process))
+        .filter(|process| {
+            let pid = process.pid().as_u32();
+            pid != own_pid
+                && !managed_pid.is_some_and(|managed| is_descendant(system, pid, managed))
+        })
+        .collect()
+}
+
+fn spawned_port(process: &Process) -> Option<u16> {
+    let prefix = format!("{}=", API_PORT_ENV);
+    process.environ().iter().find_map(|var| {
+        var.to_string_lossy()
+            .strip_prefix(&prefix)
+            .and_then(|port| port.trim().parse().ok())
+    })
+}
+
+// A Node process that we spawned (it carries AEGIS_API_PORT) or that runs from an AEGIS checkout
+fn looks_like_aegis(process: &Process) -> bool {
+    let is_node = process
+        .name()
+        .to_string_lossy()
+        .to_lowercase()
+        .starts_with("node");
+    if !is_node {
+        return false;
+    }
+
+    let has_port_env = process.environ().iter().any(|var| {
+        var.to_string_lossy()
+            .starts_with(&format!("{}=", API_PORT_ENV))
+    });
+    let mentions_aegis = |text: &OsStr| text.to_string_lossy().to_lowercase().contains("aegis");
+
+    has_port_env
+        || process.cmd().iter().any(|arg| mentions_aegis(arg))
+        || process
+            .cwd()
+            .is_some_and(|cwd| mentions_aegis(cwd.as_os_str()))
+}
+
+// Whether `pid` is `ancestor` or runs somewhere below it (npm -> node, cmd -> npm -> node)
+fn is_descendant(system: &System, pid: u32, ancestor: u32) -> bool {
+    let ancestor = Pid::from_u32(ancestor);
+    let mut current = Some(Pid::from_u32(pid));
+    while let Some(pid) = current {
+        if pid == ancestor {
+            return true;
+        }
+        current = system.process(pid).and_then(Process::parent);
+    }
+    false
+}
@@ -0,0 +1,202 @@
+// ==============================================================================
+// file_id: SOM-SCR-0075-v1.0.0
+// name: startup.rs
+// description: AEGIS Desktop - Launch-time backend startup and window gating
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, startup]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::cli;
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::health;
+use crate::i18n;
+use crate::logs::{LogStream, ServerLogs};
+use crate::notifications::notify;
+use crate::runtime::NODE_INSTALL_URL;
+use crate::server::{
+    start_api_server, ApiServerState, StartError, StartupProgress, STARTUP_PROGRESS_EVENT,
+};
+use crate::windows::{
+    close_splash_window, navigate_main_window, reveal_main_window, show_main_window, MAIN_WINDOW,
+    SPLASH_WINDOW,
+};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use tauri_plugin_shell::ShellExt;
+
+// Event the dashboard listens for to show an error banner after a slow or failed start
+pub const STARTUP_BANNER_EVENT: &str = "startup-banner";
+
+// Stderr lines included in the startup failure dialog
+const STARTUP_FAILURE_LOG_LINES: usize = 20;
+
+const GATE_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Clone, Serialize)]
+struct StartupBanner {
+    message: String,
+}
+
+// Forward a start step to every window as an `api-startup-progress` event
+pub fn report_progress(app: &AppHandle, progress: StartupProgress) {
+    tracing::debug!(?progress, "api startup progress");
+    let _ = app.emit(STARTUP_PROGRESS_EVENT, progress);
+}
+
+// Start the backend off the main thread so the splash can render while it boots
+pub fn spawn_api_server(app: AppHandle) {
+    let state = app.state::<ApiServerState>();
+    if !state.begin_start() {
+        return;
+    }
+    std::thread::spawn(move || {
+        let state = app.state::<ApiServerState>();
+        let config = app.state::<ConfigStore>().api();
+        let client = app.state::<ApiClient>();
+
+        let result = {
+            let mut process_guard = state.lock_process();
+            let progress = |progress| report_progress(&app, progress);
+            start_api_server(&config, &client, &app.state::<ServerLogs>(), &progress).map(
+                |started| {
+                    tracing::info!(
+                        port = config.port,
+                        attempts = started.attempts,
+                        "api server started at launch"
+                    );
+                    state.record_start(&started);
+                    *process_guard = Some(started.process);
+                },
+            )
+        };
+        state.finish_start();
+
+        if let Err(e) = result {
+            tracing::error!(error = %e, "could not start api server automatically");
+            let banner = i18n::tf(&app, "banner.startup_failed", &[("error", &e.to_string())]);
+            // Only a launch waiting on the splash is swapped to the main window. Headless,
+            // minimized and login launches stay in the tray with the notification and dialog.
+            if !cli::headless() && app.get_webview_window(SPLASH_WINDOW).is_some() {
+                finish_gate(&app, Some(banner));
+            } else {
+                show_banner(&app, banner);
+            }
+            match e {
+                StartError::RuntimeNotFound(program) => report_missing_node(&app, program),
+                e => report_startup_failure(&app, &e.to_string()),
+            }
+        }
+    });
+}
+
+// Keep the main window hidden until the backend answers, showing it anyway once the timeout passes
+pub fn spawn_health_gate(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let had_splash = app.get_webview_window(SPLASH_WINDOW).is_some();
+        let deadline = Instant::now() + app.state::<ConfigStore>().api().splash_timeout();
+
+        loop {
+            let healthy = {
+                let client = app.state::<ApiClient>();
+                let config = app.state::<ConfigStore>().api();
+                health::probe(&client, &config).await
+            };
+            if healthy {
+                finish_gate(&app, None);
+                return;
+            }
+            // A failed start or an early show_main_window already closed the splash
+            if had_splash && app.get_webview_window(SPLASH_WINDOW).is_none() {
+                return;
+            }
+            if Instant::now() >= deadline {
+                finish_gate(&app, Some(i18n::t(&app, "banner.startup_slow")));
+                return;
+            }
+            tokio::time::sleep(GATE_POLL_INTERVAL).await;
+        }
+    });
+}
+
+// Swap the splash for the main window, optionally with an error banner
+fn finish_gate(app: &AppHandle, banner: Option<String>) {
+    close_splash_window(app);
+    reveal_main_window(app);
+
+    if let Some(message) = banner {
+        show_banner(app, message);
+    }
+}
+
+// Banner for a main window that already exists; never creates one
+fn show_banner(app: &AppHandle, message: String) {
+    if app.get_webview_window(MAIN_WINDOW).is_some() {
+        let _ = app.emit_to(MAIN_WINDOW, STARTUP_BANNER_EVENT, StartupBanner { message });
+    }
+}
+
+// Node isn't installed, so no amount of retrying helps; point the user at the installer
+fn report_missing_node(app: &AppHandle, program: &str) {
+    notify(app, &i18n::t(app, "notification.node_missing"));
+
+    let message = i18n::tf(app, "dialog.node.message", &[("program", program)]);
+    let handle = app.clone();
+    app.dialog()
+        .message(message)
+        .title(i18n::t(app, "dialog.node.title"))
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            i18n::t(app, "dialog.node.install"),
+            i18n::t(app, "dialog.dismiss"),
+        ))
+        .show(move |install| {
+            if install {
+                // Shell::open is deprecated in favour of tauri-plugin-opener, which this app doesn't ship yet
+                #[allow(deprecated)]
+                let result = handle.shell().open(NODE_INSTALL_URL, None);
+                if let Err(e) = result {
+                    tracing::warn!(error = %e, "could not open Node.js download page");
+                }
+            }
+        });
+}
+
+// Tell the user the backend didn't come up at launch, with the captured stderr for context.
+// Desktop notifications can't carry a click handler, so the dialog offers the troubleshooting link.
+fn report_startup_failure(app: &AppHandle, error: &str) {
+    notify(app, &i18n::t(app, "notification.startup_failed"));
+
+    let stderr = app
+        .state::<ServerLogs>()
+        .recent(LogStream::Stderr, STARTUP_FAILURE_LOG_LINES);
+    let mut message = i18n::tf(app, "dialog.startup.message", &[("error", error)]);
+    if !stderr.is_empty() {
+        message.push_str("\n\n");
+        message.push_str(&i18n::t(app, "dialog.startup.output"));
+        message.push('\n');
+        message.push_str(&stderr.join("\n"));
+    }
+
+    let handle = app.clone();
+    app.dialog()
+        .message(message)
+        .title(i18n::t(app, "dialog.startup.title"))
+        .kind(MessageDialogKind::Error)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            i18n::t(app, "dialog.startup.troubleshoot"),
+            i18n::t(app, "dialog.dismiss"),
+        ))
+        .show(move |troubleshoot| {
+            if troubleshoot {
+                show_main_window(&handle);
+                let _ = navigate_main_window(&handle, "/troubleshooting");
+            }
+        });
+}
@@ -0,0 +1,165 @@
+// ==============================================================================
+// file_id: SOM-SCR-0069-v1.0.0
+// name: status.rs
+// description: AEGIS Desktop - Typed view of the API server's /api/status
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, status]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::{new_request_id, ApiClient};
+use crate::config::ApiServerConfig;
+use crate::errors::CommandError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemStatus {
+    pub api_running: bool,
+    pub uptime_secs: u64,
+    pub version: String,
+    pub components: Vec<ComponentStatus>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentStatus {
+    pub name: String,
+    pub connected: bool,
+    pub running: bool,
+}
+
+impl SystemStatus {
+    // Plain-text summary for pasting into a bug report or chat
+    pub fn to_text(&self) -> String {
+        let uptime = self.uptime_secs;
+        let mut text = format!(
+            "AEGIS {}\nAPI server: {}\nUptime: {}h {}m {}s\n",
+            self.version,
+            if self.api_running {
+                "running"
+            } else {
+                "stopped"
+            },
+            uptime / 3600,
+            uptime % 3600 / 60,
+            uptime % 60,
+        );
+        for component in &self.components {
+            text.push_str(&format!(
+                "{}: {}, {}\n",
+                component.name,
+                if component.connected {
+                    "connected"
+                } else {
+                    "disconnected"
+                },
+                if component.running {
+                    "running"
+                } else {
+                    "not running"
+                },
+            ));
+        }
+        text
+    }
+}
+
+// Wire format returned by the Express server
+#[derive(Deserialize)]
+struct StatusResponse {
+    aegis: AegisInfo,
+    dmbt: ServiceInfo,
+    #[serde(rename = "ghostShell")]
+    ghost_shell: ServiceInfo,
+}
+
+#[derive(Deserialize)]
+struct AegisInfo {
+    version: String,
+    uptime: f64,
+}
+
+#[derive(Deserialize)]
+struct ServiceInfo {
+    connected: bool,
+    #[serde(default, alias = "agentRunning", alias = "proxyRunning")]
+    running: bool,
+}
+
+impl From<StatusResponse> for SystemStatus {
+    fn from(response: StatusResponse) -> Self {
+        let component = |name: &str, info: ServiceInfo| ComponentStatus {
+            name: name.to_string(),
+            connected: info.connected,
+            running: info.running,
+        };
+
+        Self {
+            api_running: true,
+            uptime_secs: response.aegis.uptime.max(0.0) as u64,
+            version: response.aegis.version,
+            components: vec![
+                component("dmbt", response.dmbt),
+                component("ghostShell", response.ghost_shell),
+            ],
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BusyResponse {
+    busy: bool,
+}
+
+// Whether the server reports in-flight work that quitting would interrupt
+pub async fn fetch_busy(client: &ApiClient, config: &ApiServerConfig) -> Result<bool, String> {
+    let response = client
+        .get_api(config, "/api/busy", &new_request_id())
+        .await?;
+    serde_json::from_str::<BusyResponse>(&response.body)
+        .map(|body| body.busy)
+        .map_err(|e| format!("Malformed busy response from API server: {}", e))
+}
+
+// Raw /api/status body, passed through untouched
+pub async fn fetch_raw(
+    client: &ApiClient,
+    config: &ApiServerConfig,
+) -> Result<String, CommandError> {
+    request_status(client, config, &new_request_id()).await
+}
+
+// GET /api/status with an X-Request-Id, logging the outcome under that ID
+async fn request_status(
+    client: &ApiClient,
+    config: &ApiServerConfig,
+    request_id: &str,
+) -> Result<String, CommandError> {
+    match client.get_api(config, "/api/status", request_id).await {
+        Ok(response) => {
+            tracing::debug!(request_id, "status request succeeded");
+            Ok(response.body)
+        }
+        Err(e) => {
+            tracing::debug!(request_id, error = %e, "status request failed");
+            Err(e.with_request_id(request_id))
+        }
+    }
+}
+
+pub async fn fetch(
+    client: &ApiClient,
+    config: &ApiServerConfig,
+) -> Result<SystemStatus, CommandError> {
+    let request_id = new_request_id();
+    let body = request_status(client, config, &request_id).await?;
+    serde_json::from_str::<StatusResponse>(&body)
+        .map(SystemStatus::from)
+        .map_err(|e| {
+            tracing::debug!(request_id, error = %e, "malformed status response");
+            CommandError::Backend(format!("Malformed status response from API server: {}", e))
+                .with_request_id(&request_id)
+        })
+}
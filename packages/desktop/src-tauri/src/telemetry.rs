@@ -0,0 +1,69 @@
+// ==============================================================================
+// file_id: SOM-SCR-0079-v1.0.0
+// name: telemetry.rs
+// description: AEGIS Desktop - Structured logging to stdout and the log file
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, logging]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::logs::ServerLogs;
+use std::io::{self, Write};
+use tracing_subscriber::fmt::writer::MakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::EnvFilter;
+
+// Filter directives, e.g. `AEGIS_LOG=debug` or `AEGIS_LOG=aegis_desktop=trace,reqwest=warn`
+pub const LOG_FILTER_ENV: &str = "AEGIS_LOG";
+
+const DEFAULT_FILTER: &str = "info";
+
+// Install the global subscriber. Events are always printed; they also reach
+// the rotating log file once setup attaches it to `logs`.
+pub fn init(logs: &ServerLogs) {
+    let filter = EnvFilter::try_from_env(LOG_FILTER_ENV).unwrap_or_else(|e| {
+        if std::env::var_os(LOG_FILTER_ENV).is_some() {
+            eprintln!("Warning: Ignoring invalid {}: {}", LOG_FILTER_ENV, e);
+        }
+        EnvFilter::new(DEFAULT_FILTER)
+    });
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(LogFileWriter(logs.clone())),
+        )
+        .init();
+}
+
+// Routes formatted events into the log file; drops them until one is attached
+struct LogFileWriter(ServerLogs);
+
+impl<'a> MakeWriter<'a> for LogFileWriter {
+    type Writer = LogFileEvent<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogFileEvent(&self.0)
+    }
+}
+
+struct LogFileEvent<'a>(&'a ServerLogs);
+
+impl Write for LogFileEvent<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(file) = self.0.file() {
+            file.write_raw(String::from_utf8_lossy(buf).into_owned());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
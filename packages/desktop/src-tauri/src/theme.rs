@@ -0,0 +1,132 @@
+// ==============================================================================
+// file_id: SOM-SCR-0091-v1.0.0
+// name: theme.rs
+// description: AEGIS Desktop - Light/dark theme preference for the windows and tray menu
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, theme]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use crate::i18n;
+use crate::tray;
+use crate::windows::MAIN_WINDOW;
+use serde::{Deserialize, Serialize};
+use tauri::menu::{CheckMenuItem, IsMenuItem, Submenu};
+use tauri::{AppHandle, Emitter, Manager, Theme, Wry};
+
+// Emitted with the theme now in effect ("light" or "dark"), including OS changes under `system`
+pub const THEME_CHANGED_EVENT: &str = "theme-changed";
+
+const MENU_ID_PREFIX: &str = "theme:";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreference {
+    // Follow the OS setting
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemePreference {
+    const ALL: [Self; 3] = [Self::System, Self::Light, Self::Dark];
+
+    // Theme to force on the windows; None lets them follow the OS
+    pub fn as_theme(self) -> Option<Theme> {
+        match self {
+            Self::System => None,
+            Self::Light => Some(Theme::Light),
+            Self::Dark => Some(Theme::Dark),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+
+    fn menu_id(self) -> String {
+        format!("{}{}", MENU_ID_PREFIX, self.as_str())
+    }
+
+    // The preference behind a tray menu item id, if it is one of ours
+    pub fn from_menu_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.menu_id() == id)
+    }
+}
+
+pub fn current(app: &AppHandle) -> ThemePreference {
+    app.try_state::<ConfigStore>()
+        .map(|config| config.preferences().theme)
+        .unwrap_or_default()
+}
+
+// Build the tray's Theme submenu with the current preference checked
+pub fn tray_submenu(app: &AppHandle) -> tauri::Result<Submenu<Wry>> {
+    let selected = current(app);
+    let items = ThemePreference::ALL
+        .into_iter()
+        .map(|theme| {
+            CheckMenuItem::with_id(
+                app,
+                theme.menu_id(),
+                i18n::t(app, &format!("tray.theme.{}", theme.as_str())),
+                true,
+                theme == selected,
+                None::<&str>,
+            )
+        })
+        .collect::<tauri::Result<Vec<_>>>()?;
+
+    let entries: Vec<&dyn IsMenuItem<Wry>> = items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<Wry>)
+        .collect();
+    Submenu::with_id_and_items(app, "theme", i18n::t(app, "tray.theme"), true, &entries)
+}
+
+// Apply a theme to every window and the tray menu, then tell the frontend
+pub fn apply(app: &AppHandle, preference: ThemePreference) {
+    app.set_theme(preference.as_theme());
+    sync_menu(app, preference);
+
+    let effective = preference.as_theme().or_else(|| {
+        app.get_webview_window(MAIN_WINDOW)
+            .and_then(|window| window.theme().ok())
+    });
+    if let Some(theme) = effective {
+        let _ = app.emit(THEME_CHANGED_EVENT, theme);
+    }
+}
+
+// Save and apply a new theme preference
+pub fn set(app: &AppHandle, preference: ThemePreference) -> Result<(), String> {
+    app.state::<ConfigStore>()
+        .update_preferences(|prefs| prefs.theme = preference)?;
+    apply(app, preference);
+    tracing::info!(?preference, "theme updated");
+    Ok(())
+}
+
+// Windows already follow the OS under `system`; pass the change on to the frontend
+pub fn handle_os_change(app: &AppHandle, theme: Theme) {
+    if current(app) == ThemePreference::System {
+        tracing::debug!(?theme, "os theme changed");
+        let _ = app.emit(THEME_CHANGED_EVENT, theme);
+    }
+}
+
+// Check only the selected item; clicking a check item toggles it regardless
+fn sync_menu(app: &AppHandle, preference: ThemePreference) {
+    for theme in ThemePreference::ALL {
+        tray::set_checked(app, &theme.menu_id(), theme == preference);
+    }
+}
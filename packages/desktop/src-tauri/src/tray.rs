@@ -0,0 +1,111 @@
+// ==============================================================================
+// file_id: SOM-SCR-0093-v1.0.0
+// name: tray.rs
+// description: AEGIS Desktop - Handle to the current tray menu so it can be rebuilt and updated
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, tray]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::config::ConfigStore;
+use crate::health_poller::TRAY_ID;
+use crate::preferences::TrayClick;
+use crate::windows::show_main_window;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::menu::{Menu, MenuItemKind, Submenu};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconEvent};
+use tauri::{AppHandle, Manager, Wry};
+
+// Some platforms report one double-click as two clicks; later ones inside this window are dropped
+const CLICK_DEBOUNCE: Duration = Duration::from_millis(300);
+
+// The menu attached to the tray, replaced whenever it is rebuilt (e.g. on a locale change)
+#[derive(Default)]
+pub struct TrayMenu(Mutex<Option<Menu<Wry>>>);
+
+// When the tray icon last showed the main window, for debouncing
+#[derive(Default)]
+pub struct TrayClickState(Mutex<Option<Instant>>);
+
+// Show the main window on the configured left-click, ignoring repeats within CLICK_DEBOUNCE
+pub fn handle_icon_event(app: &AppHandle, event: &TrayIconEvent) {
+    let trigger = app
+        .try_state::<ConfigStore>()
+        .map(|config| config.preferences().tray_show_click)
+        .unwrap_or_default();
+    let matches = matches!(
+        (trigger, event),
+        (
+            TrayClick::Single,
+            TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            },
+        ) | (
+            TrayClick::Double,
+            TrayIconEvent::DoubleClick {
+                button: MouseButton::Left,
+                ..
+            },
+        )
+    );
+    if !matches {
+        return;
+    }
+
+    let now = Instant::now();
+    if let Ok(mut last) = app.state::<TrayClickState>().0.lock() {
+        if last.is_some_and(|at| now.duration_since(at) < CLICK_DEBOUNCE) {
+            return;
+        }
+        *last = Some(now);
+    }
+    show_main_window(app);
+}
+
+// Attach a freshly built menu to the tray and remember it for later lookups
+pub fn set_menu(app: &AppHandle, menu: Menu<Wry>) -> tauri::Result<()> {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        tray.set_menu(Some(menu.clone()))?;
+    }
+    if let Ok(mut current) = app.state::<TrayMenu>().0.lock() {
+        *current = Some(menu);
+    }
+    Ok(())
+}
+
+// Set a check item's state by id, looking one level into submenus
+pub fn set_checked(app: &AppHandle, id: &str, checked: bool) {
+    if let Some(item) = find(app, id)
+        .as_ref()
+        .and_then(|item| item.as_check_menuitem())
+    {
+        let _ = item.set_checked(checked);
+    }
+}
+
+// A submenu of the current tray menu by id, for updating its entries in place
+pub fn submenu(app: &AppHandle, id: &str) -> Option<Submenu<Wry>> {
+    find(app, id)?.as_submenu().cloned()
+}
+
+fn find(app: &AppHandle, id: &str) -> Option<MenuItemKind<Wry>> {
+    let menu = app
+        .state::<TrayMenu>()
+        .0
+        .lock()
+        .ok()
+        .and_then(|current| current.clone())?;
+
+    menu.get(id).or_else(|| {
+        menu.items()
+            .ok()?
+            .iter()
+            .find_map(|item| item.as_submenu()?.get(id))
+    })
+}
@@ -0,0 +1,93 @@
+// ==============================================================================
+// file_id: SOM-SCR-0108-v1.0.0
+// name: unix_socket.rs
+// description: AEGIS Desktop - Minimal HTTP/1.1 client for the API server's Unix socket
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, http, unix]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::REQUEST_ID_HEADER;
+use crate::errors::CommandError;
+use hyper::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE, HOST};
+use hyper::{Body, Method, Request, StatusCode};
+use std::path::Path;
+use std::time::Duration;
+use tokio::net::UnixStream;
+use zeroize::Zeroizing;
+
+// A request to send over the socket; `json` is sent as the body with a JSON content type
+pub struct SocketRequest<'a> {
+    pub method: Method,
+    pub path: &'a str,
+    pub json: Option<String>,
+    pub token: &'a str,
+    pub request_id: &'a str,
+}
+
+// One request per connection; API calls from the shell are too infrequent to pool
+pub async fn send(
+    socket: &Path,
+    request: SocketRequest<'_>,
+    timeout: Duration,
+) -> Result<(StatusCode, String), CommandError> {
+    match tokio::time::timeout(timeout, exchange(socket, request)).await {
+        Ok(result) => result,
+        Err(_) => Err(CommandError::Timeout(format!(
+            "API server timed out after {}ms",
+            timeout.as_millis()
+        ))),
+    }
+}
+
+async fn exchange(
+    socket: &Path,
+    request: SocketRequest<'_>,
+) -> Result<(StatusCode, String), CommandError> {
+    let stream = UnixStream::connect(socket).await.map_err(|e| {
+        CommandError::NotRunning(format!("Could not connect to {}: {}", socket.display(), e))
+    })?;
+    let (mut sender, connection) = hyper::client::conn::handshake(stream)
+        .await
+        .map_err(|e| CommandError::Backend(e.to_string()))?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::debug!(error = %e, "unix socket connection closed with an error");
+        }
+    });
+
+    // Marked sensitive like reqwest's bearer_auth, so the token stays out of Debug output
+    let bearer = Zeroizing::new(format!("Bearer {}", request.token));
+    let mut authorization =
+        HeaderValue::from_str(&bearer).map_err(|e| CommandError::Config(e.to_string()))?;
+    authorization.set_sensitive(true);
+    let mut builder = Request::builder()
+        .method(request.method)
+        .uri(request.path)
+        .header(HOST, "localhost")
+        .header(AUTHORIZATION, authorization)
+        .header(REQUEST_ID_HEADER, request.request_id);
+    let body = match request.json {
+        Some(json) => {
+            builder = builder.header(CONTENT_TYPE, "application/json");
+            Body::from(json)
+        }
+        None => Body::empty(),
+    };
+    let request = builder
+        .body(body)
+        .map_err(|e| CommandError::Config(e.to_string()))?;
+
+    let response = sender
+        .send_request(request)
+        .await
+        .map_err(|e| CommandError::Backend(e.to_string()))?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body())
+        .await
+        .map_err(|e| CommandError::Backend(e.to_string()))?;
+    Ok((status, String::from_utf8_lossy(&body).into_owned()))
+}
@@ -0,0 +1,252 @@
+// ==============================================================================
+// file_id: SOM-SCR-0083-v1.0.0
+// name: updates.rs
+// description: AEGIS Desktop - Checking the release feed for a newer version
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, updater]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::{ApiServerConfig, ConfigStore};
+use crate::config_watcher;
+use crate::i18n;
+use crate::lockdown::LockdownState;
+use crate::notifications::notify;
+use crate::server::{stop_api_server, wait_for_port_release, ApiServerState};
+use crate::startup;
+use reqwest::Url;
+use serde::Serialize;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use tauri_plugin_updater::{Update, Updater, UpdaterExt};
+
+pub const DEFAULT_UPDATE_FEED_URL: &str =
+    "https://github.com/SoMaCoSF/aegis/releases/latest/download/latest.json";
+
+const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// How long the stopped backend's port may stay bound before the install is abandoned
+const PORT_RELEASE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateCheck {
+    pub available: bool,
+    pub current_version: String,
+    pub latest_version: Option<String>,
+    pub notes: Option<String>,
+}
+
+// api.update_pubkey, else plugins.updater.pubkey from tauri.conf.json. Empty counts as
+// unset: the repository ships without a key, so updates stay off until one is configured.
+fn updater_pubkey(app: &AppHandle, config: &ApiServerConfig) -> Option<String> {
+    config
+        .update_pubkey
+        .clone()
+        .or_else(|| {
+            app.config()
+                .plugins
+                .0
+                .get("updater")
+                .and_then(|updater| updater.get("pubkey"))
+                .and_then(|pubkey| pubkey.as_str())
+                .map(String::from)
+        })
+        .filter(|pubkey| !pubkey.trim().is_empty())
+}
+
+fn updater(app: &AppHandle, config: &ApiServerConfig) -> Result<Updater, String> {
+    let pubkey = updater_pubkey(app, config).ok_or(
+        "Updates are disabled: no updater public key is configured \
+         (set api.update_pubkey or plugins.updater.pubkey)",
+    )?;
+    let feed = config
+        .update_feed_url
+        .as_deref()
+        .unwrap_or(DEFAULT_UPDATE_FEED_URL);
+    let feed =
+        Url::parse(feed).map_err(|e| format!("Invalid update feed URL {:?}: {}", feed, e))?;
+
+    app.updater_builder()
+        .endpoints(vec![feed])
+        .map_err(|e| e.to_string())?
+        .timeout(UPDATE_CHECK_TIMEOUT)
+        .pubkey(pubkey)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+// Query the configured feed; network failures come back as a readable error
+pub async fn check(app: &AppHandle) -> Result<UpdateCheck, String> {
+    let updater = updater(app, &app.state::<ConfigStore>().api())?;
+
+    let current_version = app.package_info().version.to_string();
+    match updater.check().await {
+        Ok(Some(update)) => {
+            tracing::info!(latest = %update.version, "update available");
+            Ok(UpdateCheck {
+                available: true,
+                current_version,
+                latest_version: Some(update.version),
+                notes: update.body,
+            })
+        }
+        Ok(None) => Ok(UpdateCheck {
+            available: false,
+            current_version,
+            latest_version: None,
+            notes: None,
+        }),
+        Err(e) => {
+            tracing::warn!(error = %e, "update check failed");
+            Err(format!("Could not reach the update server: {}", e))
+        }
+    }
+}
+
+// The available update and its installer. `download` checks the installer's signature
+// against the configured key, so an unsigned or tampered package fails here.
+async fn download_verified(app: &AppHandle) -> Result<(Update, Vec<u8>), String> {
+    let updater = updater(app, &app.state::<ConfigStore>().api())?;
+    let update = updater
+        .check()
+        .await
+        .map_err(|e| format!("Could not reach the update server: {}", e))?
+        .ok_or("AEGIS is already up to date")?;
+    let bytes = update
+        .download(|_, _| {}, || {})
+        .await
+        .map_err(|e| format!("Could not download AEGIS {}: {}", update.version, e))?;
+    Ok((update, bytes))
+}
+
+// Download and verify the update while the backend keeps serving; it is only drained
+// for the swap itself. Only returns on failure: a successful install relaunches the
+// app, and the backend comes back up with it.
+pub async fn install(app: &AppHandle) -> Result<(), String> {
+    app.state::<LockdownState>().ensure_unlocked()?;
+    let (update, bytes) = download_verified(app).await?;
+    tracing::info!(version = %update.version, "update downloaded and verified, draining backend");
+
+    let app = app.clone();
+    tauri::async_runtime::spawn_blocking(move || apply(&app, &update, &bytes))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// Teardown order matters here. The config watcher goes first so a reload can't restart
+// anything mid-drain. The process lock is held through the install so the watchdog and
+// manual starts can't bring the backend back, and released before relaunching because
+// the exit handlers run on the main thread. The single-instance lock goes last, or the
+// relaunched process would hand its arguments to this one and quit.
+fn apply(app: &AppHandle, update: &Update, bytes: &[u8]) -> Result<(), String> {
+    config_watcher::stop(app);
+    let state = app.state::<ApiServerState>();
+    let config = app.state::<ConfigStore>().api();
+    stop_api_server(&state, config.shutdown_grace());
+
+    let process_guard = state.lock_process();
+    let result = if process_guard.is_some() {
+        Err("The backend was started again while the update was being prepared".to_string())
+    } else {
+        wait_for_port_release(config.port, PORT_RELEASE_TIMEOUT)
+            .map_err(|e| e.to_string())
+            .and_then(|()| {
+                // The Windows installer ends this process itself
+                #[cfg(target_os = "windows")]
+                tauri_plugin_single_instance::destroy(app);
+                update
+                    .install(bytes)
+                    .map_err(|e| format!("Could not install AEGIS {}: {}", update.version, e))
+            })
+    };
+    drop(process_guard);
+
+    if let Err(e) = result {
+        tracing::error!(error = %e, "update not installed, restarting backend");
+        config_watcher::spawn(app.clone());
+        startup::spawn_api_server(app.clone());
+        return Err(e);
+    }
+
+    tracing::info!(version = %update.version, "update installed, relaunching");
+    app.state::<ApiClient>().wipe_token();
+    tauri_plugin_single_instance::destroy(app);
+    app.restart();
+}
+
+// Install from the tray; success relaunches, so only failures are reported
+fn install_from_tray(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = install(&app).await {
+            notify(&app, &format!("Update failed: {}", e));
+        }
+    });
+}
+
+// Tray flow: check, notify when there is something new, and report the result in a
+// dialog that offers to install an available update
+pub fn check_from_tray(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let (title, message, kind, available) = match check(&app).await {
+            Ok(result) if result.available => {
+                let latest = result.latest_version.unwrap_or_default();
+                notify(&app, &format!("AEGIS {} is available", latest));
+                let mut message = i18n::tf(
+                    &app,
+                    "dialog.update.available.message",
+                    &[("latest", &latest), ("current", &result.current_version)],
+                );
+                if let Some(notes) = result.notes.filter(|notes| !notes.trim().is_empty()) {
+                    message.push_str("\n\n");
+                    message.push_str(&notes);
+                }
+                (
+                    i18n::t(&app, "dialog.update.available.title"),
+                    message,
+                    MessageDialogKind::Info,
+                    true,
+                )
+            }
+            Ok(result) => (
+                i18n::t(&app, "dialog.update.none.title"),
+                i18n::tf(
+                    &app,
+                    "dialog.update.none.message",
+                    &[("current", &result.current_version)],
+                ),
+                MessageDialogKind::Info,
+                false,
+            ),
+            Err(e) => (
+                i18n::t(&app, "dialog.update.failed.title"),
+                i18n::tf(&app, "dialog.update.failed.message", &[("error", &e)]),
+                MessageDialogKind::Warning,
+                false,
+            ),
+        };
+
+        let dialog = app.dialog().message(message).title(title).kind(kind);
+        if !available {
+            dialog.show(|_| {});
+            return;
+        }
+        let handle = app.clone();
+        dialog
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                i18n::t(&app, "dialog.update.install"),
+                i18n::t(&app, "dialog.update.later"),
+            ))
+            .show(move |install| {
+                if install {
+                    install_from_tray(&handle);
+                }
+            });
+    });
+}
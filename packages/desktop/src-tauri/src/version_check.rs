@@ -0,0 +1,118 @@
+// ==============================================================================
+// file_id: SOM-SCR-0107-v1.0.0
+// name: version_check.rs
+// description: AEGIS Desktop - Detects a backend whose major version doesn't match the app
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, diagnostics, updater]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::errors::CommandError;
+use crate::notifications::notify;
+use crate::status;
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+// Sent when a check first finds a mismatch, with the VersionCompatibility
+pub const VERSION_MISMATCH_EVENT: &str = "backend-version-mismatch";
+
+// The shell and the backend are released together
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VersionCompatibility {
+    pub expected: String,
+    pub backend: String,
+    // Same major version, or either version couldn't be parsed
+    pub compatible: bool,
+    pub message: Option<String>,
+}
+
+// The last mismatch found; cleared by a check that passes
+#[derive(Default)]
+pub struct VersionState {
+    mismatch: Mutex<Option<VersionCompatibility>>,
+}
+
+impl VersionState {
+    pub fn mismatch(&self) -> Option<VersionCompatibility> {
+        self.mismatch
+            .lock()
+            .ok()
+            .and_then(|mismatch| mismatch.clone())
+    }
+}
+
+fn compare(expected: &str, backend: &str) -> VersionCompatibility {
+    let message = match (major(expected), major(backend)) {
+        (Some(want), Some(have)) if want != have => Some(format!(
+            "The AEGIS backend is version {} but this app expects {}.x. Reinstall AEGIS so \
+             both match.",
+            backend, want
+        )),
+        (Some(_), Some(_)) => None,
+        _ => {
+            tracing::warn!(
+                expected,
+                backend,
+                "could not parse version for compatibility check"
+            );
+            None
+        }
+    };
+    VersionCompatibility {
+        expected: expected.to_string(),
+        backend: backend.to_string(),
+        compatible: message.is_none(),
+        message,
+    }
+}
+
+fn major(version: &str) -> Option<u64> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .next()?
+        .parse()
+        .ok()
+}
+
+// Ask the backend for its version and compare. A new mismatch is announced once, by
+// notification and VERSION_MISMATCH_EVENT; the watchdog won't restart a mismatched
+// backend that crashes.
+pub async fn check(app: &AppHandle) -> Result<VersionCompatibility, CommandError> {
+    let config = app.state::<ConfigStore>().api();
+    let status = status::fetch(&app.state::<ApiClient>(), &config).await?;
+    let expected = config
+        .expected_backend_version
+        .as_deref()
+        .unwrap_or(APP_VERSION);
+    let result = compare(expected, &status.version);
+
+    let announce = match app.state::<VersionState>().mismatch.lock() {
+        Ok(mut mismatch) if result.compatible => {
+            *mismatch = None;
+            false
+        }
+        Ok(mut mismatch) if mismatch.as_ref() != Some(&result) => {
+            *mismatch = Some(result.clone());
+            true
+        }
+        _ => false,
+    };
+    if announce {
+        tracing::warn!(expected, backend = %result.backend, "backend version mismatch");
+        if let Some(message) = &result.message {
+            notify(app, message);
+        }
+        let _ = app.emit(VERSION_MISMATCH_EVENT, &result);
+    }
+    Ok(result)
+}
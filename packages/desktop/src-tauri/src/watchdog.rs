@@ -0,0 +1,212 @@
+// ==============================================================================
+// file_id: SOM-SCR-0067-v1.0.0
+// name: watchdog.rs
+// description: AEGIS Desktop - Restarts the API server when it exits unexpectedly
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, watchdog]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::logs::ServerLogs;
+use crate::notifications::notify;
+use crate::safe_mode::{self, SafeModeState};
+use crate::server::{start_api_server, ApiServerState};
+use crate::startup;
+use crate::version_check::VersionState;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+const RESTART_WINDOW: Duration = Duration::from_secs(60);
+
+// How long pause_watchdog lasts when the caller doesn't say
+pub const DEFAULT_PAUSE: Duration = Duration::from_secs(30 * 60);
+
+// Restarts performed by the watchdog since launch
+#[derive(Default)]
+pub struct WatchdogState {
+    pub restart_count: AtomicU32,
+    // The watchdog thread started for this value is the live one; bumping it retires it
+    generation: AtomicU64,
+    // No restarts while set, so someone restarting the backend by hand isn't fought
+    paused: AtomicBool,
+    // When the current pause lifts by itself
+    resume_at: Mutex<Option<Instant>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PauseStatus {
+    pub paused: bool,
+    pub resumes_in_secs: Option<u64>,
+}
+
+impl WatchdogState {
+    pub fn pause(&self, duration: Duration) {
+        if let Ok(mut resume_at) = self.resume_at.lock() {
+            *resume_at = Some(Instant::now() + duration);
+        }
+        self.paused.store(true, Ordering::SeqCst);
+        tracing::info!(secs = duration.as_secs(), "watchdog paused");
+    }
+
+    pub fn resume(&self) {
+        if let Ok(mut resume_at) = self.resume_at.lock() {
+            *resume_at = None;
+        }
+        if self.paused.swap(false, Ordering::SeqCst) {
+            tracing::info!("watchdog resumed");
+        }
+    }
+
+    // Also lifts a pause whose timeout has passed
+    pub fn is_paused(&self) -> bool {
+        self.pause_status().paused
+    }
+
+    pub fn pause_status(&self) -> PauseStatus {
+        if !self.paused.load(Ordering::SeqCst) {
+            return PauseStatus {
+                paused: false,
+                resumes_in_secs: None,
+            };
+        }
+        let remaining = self
+            .resume_at
+            .lock()
+            .ok()
+            .and_then(|resume_at| *resume_at)
+            .map(|at| at.saturating_duration_since(Instant::now()));
+        if remaining.is_some_and(|remaining| remaining.is_zero()) {
+            tracing::info!("watchdog pause expired");
+            self.resume();
+            return self.pause_status();
+        }
+        PauseStatus {
+            paused: true,
+            resumes_in_secs: remaining.map(|remaining| remaining.as_secs()),
+        }
+    }
+}
+
+// Spawn the background thread that watches the managed server process.
+// A process removed by stop_api_server is never seen here, so only crashes trigger a restart.
+pub fn spawn(app: AppHandle) {
+    let generation = app
+        .state::<WatchdogState>()
+        .generation
+        .load(Ordering::SeqCst);
+    std::thread::spawn(move || {
+        let mut recent_restarts: VecDeque<Instant> = VecDeque::new();
+
+        loop {
+            std::thread::sleep(CHECK_INTERVAL);
+
+            if app
+                .state::<WatchdogState>()
+                .generation
+                .load(Ordering::SeqCst)
+                != generation
+            {
+                tracing::debug!(generation, "watchdog retired");
+                return;
+            }
+            // An exited process stays put and is restarted once the pause lifts
+            if app.state::<WatchdogState>().is_paused() {
+                continue;
+            }
+            // Safe mode already gave up; a manual start leaves it
+            if app.state::<SafeModeState>().is_active() {
+                continue;
+            }
+
+            let state = app.state::<ApiServerState>();
+            let mut process_guard = state.lock_process();
+
+            let exit_status = match process_guard.as_mut().map(|child| child.try_wait()) {
+                Some(Ok(Some(status))) => status,
+                _ => continue,
+            };
+            process_guard.take();
+            state.record_stop();
+            tracing::warn!(status = %exit_status, "api server exited unexpectedly");
+
+            // Restarting the same mismatched backend would only crash again
+            if let Some(mismatch) = app.state::<VersionState>().mismatch() {
+                let reason = mismatch.message.unwrap_or_default();
+                safe_mode::enter(&app, &reason);
+                continue;
+            }
+
+            let config = app.state::<ConfigStore>().api();
+            let now = Instant::now();
+            while recent_restarts
+                .front()
+                .is_some_and(|at| now.duration_since(*at) > RESTART_WINDOW)
+            {
+                recent_restarts.pop_front();
+            }
+
+            if recent_restarts.len() as u32 >= config.max_restarts_per_minute {
+                tracing::error!(
+                    max_per_minute = config.max_restarts_per_minute,
+                    "api server restart limit reached; watchdog paused"
+                );
+                safe_mode::enter(
+                    &app,
+                    &format!(
+                        "the backend crashed {} times within a minute, so automatic restarts \
+                         have stopped",
+                        recent_restarts.len()
+                    ),
+                );
+                continue;
+            }
+
+            recent_restarts.push_back(now);
+            app.state::<WatchdogState>()
+                .restart_count
+                .fetch_add(1, Ordering::SeqCst);
+
+            let client = app.state::<ApiClient>();
+            let progress = |progress| startup::report_progress(&app, progress);
+            match start_api_server(&config, &client, &app.state::<ServerLogs>(), &progress) {
+                Ok(started) => {
+                    tracing::info!(
+                        pid = started.process.id(),
+                        "api server restarted by watchdog"
+                    );
+                    state.record_start(&started);
+                    *process_guard = Some(started.process);
+                    notify(
+                        &app,
+                        "AEGIS backend stopped unexpectedly and was restarted.",
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "watchdog could not restart api server");
+                    notify(
+                        &app,
+                        &format!("AEGIS backend could not be restarted: {}", e),
+                    );
+                }
+            }
+        }
+    });
+}
+
+// Retire the running watchdog. It exits at its next check without touching the server;
+// one already restarting finishes first, so stop the server afterwards, then `spawn` again.
+pub fn stop(app: &AppHandle) {
+    app.state::<WatchdogState>()
+        .generation
+        .fetch_add(1, Ordering::SeqCst);
+}
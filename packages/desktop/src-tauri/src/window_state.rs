@@ -0,0 +1,194 @@
+// ==============================================================================
+// file_id: SOM-SCR-0071-v1.0.0
+// name: window_state.rs
+// description: AEGIS Desktop - Persist and restore main window geometry
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, window]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{Monitor, PhysicalPosition, PhysicalSize, WebviewWindow, Window};
+
+const STATE_FILE_NAME: &str = "window-state.json";
+
+// Minimum visible overlap (in physical pixels) for a saved position to count as on-screen
+const MIN_VISIBLE_PX: i32 = 100;
+
+// Smallest restored size in logical pixels, so a bad rescale can't leave a sliver of a window
+const MIN_LOGICAL_WIDTH: f64 = 640.0;
+const MIN_LOGICAL_HEIGHT: f64 = 480.0;
+
+// Outer position and inner size, in physical pixels
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+    // Of the monitor the bounds were captured on; files written before this was saved are 1.0
+    #[serde(default = "default_scale_factor")]
+    pub scale_factor: f64,
+}
+
+fn default_scale_factor() -> f64 {
+    1.0
+}
+
+pub struct WindowStateStore {
+    path: Option<PathBuf>,
+    bounds: Mutex<Option<WindowBounds>>,
+}
+
+impl WindowStateStore {
+    pub fn load(config_dir: Option<&Path>) -> Self {
+        let path = config_dir.map(|dir| dir.join(STATE_FILE_NAME));
+        let bounds = path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+
+        Self {
+            path,
+            bounds: Mutex::new(bounds),
+        }
+    }
+
+    // Apply saved bounds to a window that hasn't been shown yet
+    pub fn restore(&self, window: &WebviewWindow) {
+        let Some(saved) = self.bounds.lock().ok().and_then(|bounds| *bounds) else {
+            return;
+        };
+
+        let monitors = window.available_monitors().unwrap_or_default();
+        let fallback = window.primary_monitor().ok().flatten();
+        let bounds = fit_to_monitors(saved, &monitors, fallback.as_ref());
+
+        let _ = window.set_size(PhysicalSize::new(bounds.width, bounds.height));
+        let _ = window.set_position(PhysicalPosition::new(bounds.x, bounds.y));
+        if bounds.maximized {
+            let _ = window.maximize();
+        }
+    }
+
+    // Record the window's current geometry and write it to disk
+    pub fn capture(&self, window: &Window) {
+        // Minimized windows report a zero-sized geometry on some platforms
+        if window.is_minimized().unwrap_or(false) {
+            return;
+        }
+        let Ok(mut bounds) = self.bounds.lock() else {
+            return;
+        };
+
+        let maximized = window.is_maximized().unwrap_or(false);
+        let next = if maximized {
+            // Keep the restored (un-maximized) geometry so un-maximizing after relaunch works
+            match *bounds {
+                Some(previous) => WindowBounds {
+                    maximized: true,
+                    ..previous
+                },
+                None => return,
+            }
+        } else {
+            let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+                return;
+            };
+            WindowBounds {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized: false,
+                scale_factor: window.scale_factor().unwrap_or(1.0),
+            }
+        };
+
+        *bounds = Some(next);
+        self.write(&next);
+    }
+
+    fn write(&self, bounds: &WindowBounds) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        if let Ok(contents) = serde_json::to_string_pretty(bounds) {
+            let _ = std::fs::write(path, contents);
+        }
+    }
+}
+
+// Fit saved bounds to a connected monitor. The size is rescaled when that monitor's DPI
+// differs from the one they were saved on and kept between the minimum and the monitor's
+// size. Bounds whose monitor is gone are centered on the fallback one.
+fn fit_to_monitors(
+    bounds: WindowBounds,
+    monitors: &[Monitor],
+    fallback: Option<&Monitor>,
+) -> WindowBounds {
+    let saved_on = monitors.iter().find(|monitor| overlaps(&bounds, monitor));
+    let Some(monitor) = saved_on.or(fallback).or_else(|| monitors.first()) else {
+        return bounds;
+    };
+
+    let origin = monitor.position();
+    let size = monitor.size();
+    let scale_factor = monitor.scale_factor();
+    let rescale = if bounds.scale_factor > 0.0 {
+        scale_factor / bounds.scale_factor
+    } else {
+        1.0
+    };
+    let fit = |length: u32, min_logical: f64, available: u32| {
+        let min = ((min_logical * scale_factor) as u32).min(available);
+        ((length as f64 * rescale).round() as u32).clamp(min, available)
+    };
+    let width = fit(bounds.width, MIN_LOGICAL_WIDTH, size.width);
+    let height = fit(bounds.height, MIN_LOGICAL_HEIGHT, size.height);
+
+    // Keep a window that grew with the rescale inside its monitor
+    let max_x = origin.x + (size.width - width) as i32;
+    let max_y = origin.y + (size.height - height) as i32;
+    let (x, y) = if saved_on.is_some() {
+        (
+            bounds.x.clamp(origin.x, max_x),
+            bounds.y.clamp(origin.y, max_y),
+        )
+    } else {
+        (
+            origin.x + ((size.width - width) / 2) as i32,
+            origin.y + ((size.height - height) / 2) as i32,
+        )
+    };
+
+    WindowBounds {
+        x,
+        y,
+        width,
+        height,
+        maximized: bounds.maximized,
+        scale_factor,
+    }
+}
+
+fn overlaps(bounds: &WindowBounds, monitor: &Monitor) -> bool {
+    let origin = monitor.position();
+    let size = monitor.size();
+
+    let left = bounds.x.max(origin.x);
+    let right = (bounds.x + bounds.width as i32).min(origin.x + size.width as i32);
+    let top = bounds.y.max(origin.y);
+    let bottom = (bounds.y + bounds.height as i32).min(origin.y + size.height as i32);
+
+    right - left >= MIN_VISIBLE_PX && bottom - top >= MIN_VISIBLE_PX
+}
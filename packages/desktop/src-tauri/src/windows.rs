@@ -0,0 +1,386 @@
+// ==============================================================================
+// file_id: SOM-SCR-0074-v1.0.0
+// name: windows.rs
+// description: AEGIS Desktop - Main and settings window helpers
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, window]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use crate::cli;
+use crate::client::ApiClient;
+use crate::config::ConfigStore;
+use crate::lockdown::LockdownState;
+use crate::theme;
+use crate::tray;
+use crate::window_state::WindowStateStore;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{
+    utils::config::WindowConfig, AppHandle, Emitter, Manager, Url, Webview, WebviewUrl,
+    WebviewWindow, WebviewWindowBuilder, WindowEvent,
+};
+use zeroize::Zeroizing;
+
+pub const MAIN_WINDOW: &str = "main";
+pub const SETTINGS_WINDOW: &str = "settings";
+pub const SPLASH_WINDOW: &str = "splash";
+
+// Event the dashboard's router listens for; the payload is the route
+pub const NAVIGATE_EVENT: &str = "navigate";
+
+// Tray checkbox for the always_on_top preference
+pub const ALWAYS_ON_TOP_ITEM_ID: &str = "always_on_top";
+
+// Sent with `true` or `false` whenever the main window is shown or hidden
+pub const VISIBILITY_EVENT: &str = "window-visibility-changed";
+
+// Prepended to open_route_window's label, keeping route windows apart from the fixed ones
+pub const ROUTE_WINDOW_PREFIX: &str = "route-";
+
+// Main-window visibility as last sent with VISIBILITY_EVENT
+#[derive(Default)]
+pub struct MainWindowVisibility(AtomicBool);
+
+// Bumped whenever the main window gains or loses focus or is shown, so a pending
+// auto-hide can tell it has been overtaken
+#[derive(Default)]
+pub struct AutoHideState(AtomicU64);
+
+// Labels of the route windows currently open, so shutdown and lockdown can close them
+#[derive(Default)]
+pub struct RouteWindows(Mutex<BTreeSet<String>>);
+
+// Whether a webview is one of our windows showing the app's own frontend
+// (the bundled assets or the local dev server), not some page it navigated to
+pub fn is_app_webview(webview: &Webview) -> bool {
+    if ![MAIN_WINDOW, SETTINGS_WINDOW].contains(&webview.label()) {
+        return false;
+    }
+    let Ok(url) = webview.url() else {
+        return false;
+    };
+    url.scheme() == "tauri"
+        || matches!(
+            url.host_str(),
+            Some("localhost" | "127.0.0.1" | "tauri.localhost")
+        )
+}
+
+// Create the (hidden) main window from its tauri.conf.json entry with its saved geometry
+pub fn create_main_window(app: &AppHandle) -> tauri::Result<WebviewWindow> {
+    let window = WebviewWindowBuilder::from_config(app, &window_config(app, MAIN_WINDOW)?)?
+        .theme(theme::current(app).as_theme())
+        .always_on_top(always_on_top(app))
+        .build()?;
+    if let Some(store) = app.try_state::<WindowStateStore>() {
+        store.restore(&window);
+    }
+    Ok(window)
+}
+
+// Bring the main window to the front, creating it if the app started headless
+// Also closes the splash, so showing the dashboard early (tray, hotkey) ends the wait
+pub fn show_main_window(app: &AppHandle) {
+    close_splash_window(app);
+    // A tray click takes focus from the window first; don't let that blur hide it again
+    cancel_auto_hide(app);
+    let window = match app.get_webview_window(MAIN_WINDOW) {
+        Some(window) => window,
+        None => match create_main_window(app) {
+            Ok(window) => window,
+            Err(e) => {
+                tracing::warn!(error = %e, "could not create main window");
+                return;
+            }
+        },
+    };
+    let _ = window.show();
+    let _ = window.unminimize();
+    // Windows and some Linux window managers drop the flag while the window is hidden
+    let _ = window.set_always_on_top(always_on_top(app));
+    let _ = window.set_focus();
+    sync_visibility(app);
+}
+
+// show_main_window for anything the user didn't ask for (startup failures, safe mode).
+// Headless runs never get a window this way; they only have the tray. Returns whether
+// the window was shown.
+pub fn reveal_main_window(app: &AppHandle) -> bool {
+    if cli::headless() {
+        return false;
+    }
+    show_main_window(app);
+    true
+}
+
+// The saved always_on_top preference; the main window follows it whenever it exists
+pub fn always_on_top(app: &AppHandle) -> bool {
+    app.try_state::<ConfigStore>()
+        .is_some_and(|config| config.preferences().always_on_top)
+}
+
+// Save the preference, pin or unpin the main window and update the tray checkbox
+pub fn set_always_on_top(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    app.state::<ConfigStore>()
+        .update_preferences(|prefs| prefs.always_on_top = enabled)?;
+    apply_always_on_top(app);
+    Ok(())
+}
+
+// Bring the main window and tray checkbox in line with the saved preference, e.g. after
+// the config was replaced
+pub fn apply_always_on_top(app: &AppHandle) {
+    let enabled = always_on_top(app);
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW) {
+        if let Err(e) = window.set_always_on_top(enabled) {
+            tracing::warn!(error = %e, "could not change always-on-top");
+        }
+    }
+    tray::set_checked(app, ALWAYS_ON_TOP_ITEM_ID, enabled);
+}
+
+// Shown and not minimized; false before a headless run opens it
+pub fn is_main_window_visible(app: &AppHandle) -> bool {
+    app.get_webview_window(MAIN_WINDOW).is_some_and(|window| {
+        window.is_visible().unwrap_or(false) && !window.is_minimized().unwrap_or(false)
+    })
+}
+
+// Send VISIBILITY_EVENT if visibility changed since the last call. Tauri has no show/hide
+// window event, so this runs after the app shows or hides the window and on the window
+// events that come with minimizing and focus changes.
+pub fn sync_visibility(app: &AppHandle) {
+    let visible = is_main_window_visible(app);
+    let Some(state) = app.try_state::<MainWindowVisibility>() else {
+        return;
+    };
+    if state.0.swap(visible, Ordering::SeqCst) != visible {
+        let _ = app.emit(VISIBILITY_EVENT, visible);
+    }
+}
+
+// Main window focus changes: with auto_hide_on_blur, losing focus hides the window after
+// auto_hide_delay_ms unless it is focused or shown again first. Only the main window
+// hides this way, and not while one of our other windows (settings) has focus.
+pub fn handle_main_focus(app: &AppHandle, focused: bool) {
+    let generation = cancel_auto_hide(app);
+    let prefs = match app.try_state::<ConfigStore>() {
+        Some(config) => config.preferences(),
+        None => return,
+    };
+    if focused || !prefs.auto_hide_on_blur {
+        return;
+    }
+
+    let app = app.clone();
+    let delay = Duration::from_millis(prefs.auto_hide_delay_ms);
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        if current_generation(&app) != Some(generation) {
+            return;
+        }
+        let ours_focused = app
+            .webview_windows()
+            .values()
+            .any(|window| window.is_focused().unwrap_or(false));
+        if ours_focused {
+            return;
+        }
+        if let Some(window) = app.get_webview_window(MAIN_WINDOW) {
+            tracing::debug!("hiding main window after focus loss");
+            let _ = window.hide();
+            sync_visibility(&app);
+        }
+    });
+}
+
+// Invalidate any pending auto-hide, returning the new generation
+fn cancel_auto_hide(app: &AppHandle) -> u64 {
+    app.try_state::<AutoHideState>()
+        .map_or(0, |state| state.0.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+fn current_generation(app: &AppHandle) -> Option<u64> {
+    app.try_state::<AutoHideState>()
+        .map(|state| state.0.load(Ordering::SeqCst))
+}
+
+// Hide the main window if it is in front, otherwise bring it forward
+pub fn toggle_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(MAIN_WINDOW) else {
+        show_main_window(app);
+        return;
+    };
+    let visible = window.is_visible().unwrap_or(false);
+    let focused = window.is_focused().unwrap_or(false);
+
+    if visible && focused {
+        let _ = window.hide();
+        sync_visibility(app);
+    } else {
+        show_main_window(app);
+    }
+}
+
+// Point the main window's dashboard at a route such as `/status` via the SPA router
+pub fn navigate_main_window(app: &AppHandle, route: &str) -> Result<(), String> {
+    if !route.starts_with('/') {
+        return Err(format!("Route {:?} must start with '/'", route));
+    }
+    app.emit_to(MAIN_WINDOW, NAVIGATE_EVENT, route)
+        .map_err(|e| e.to_string())
+}
+
+// Focus the settings window, creating it from its tauri.conf.json entry on first use
+pub fn open_settings_window(app: &AppHandle) -> tauri::Result<()> {
+    let window = match app.get_webview_window(SETTINGS_WINDOW) {
+        Some(window) => window,
+        None => WebviewWindowBuilder::from_config(app, &window_config(app, SETTINGS_WINDOW)?)?
+            .theme(theme::current(app).as_theme())
+            .build()?,
+    };
+
+    window.show()?;
+    let _ = window.unminimize();
+    window.set_focus()
+}
+
+// Open the dashboard page at `route` (such as `/logs`) on the configured base URL in its own
+// window, or focus the one already open under `label`. The page finds the API token in
+// `window.__AEGIS_API_TOKEN__` and can't navigate away from the base URL.
+pub fn open_route_window(app: &AppHandle, route: &str, label: &str) -> Result<(), String> {
+    app.state::<LockdownState>()
+        .ensure_unlocked()
+        .map_err(|e| e.to_string())?;
+    if label.is_empty()
+        || label.len() > 64
+        || !label
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Window label {:?} must be 1-64 letters, digits, '-' or '_'",
+            label
+        ));
+    }
+    let window_label = format!("{}{}", ROUTE_WINDOW_PREFIX, label);
+    if let Some(window) = app.get_webview_window(&window_label) {
+        let _ = window.show();
+        let _ = window.unminimize();
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+
+    let base = Url::parse(&app.state::<ConfigStore>().api().base_url())
+        .map_err(|e| format!("api_base_url is not a valid URL: {}", e))?;
+    let url = route_url(&base, route)?;
+    // Only defined on the base's origin, so it never reaches another site's scripts
+    let script = Zeroizing::new(format!(
+        "if (window.location.origin === {}) {{ window.__AEGIS_API_TOKEN__ = {}; }}",
+        serde_json::to_string(&base.origin().ascii_serialization()).map_err(|e| e.to_string())?,
+        serde_json::to_string(app.state::<ApiClient>().token().as_str())
+            .map_err(|e| e.to_string())?,
+    ));
+
+    let guard = base.clone();
+    let window = WebviewWindowBuilder::new(app, &window_label, WebviewUrl::External(url))
+        .title(format!("AEGIS — {}", label))
+        .inner_size(1000.0, 700.0)
+        .theme(theme::current(app).as_theme())
+        .initialization_script(script.as_str())
+        .on_navigation(move |url| {
+            let allowed = within_base(&guard, url);
+            if !allowed {
+                tracing::warn!(%url, "blocked route window navigation outside the api base url");
+            }
+            allowed
+        })
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let routes = app.state::<RouteWindows>();
+    if let Ok(mut labels) = routes.0.lock() {
+        labels.insert(window_label.clone());
+    }
+    let handle = app.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            if let Ok(mut labels) = handle.state::<RouteWindows>().0.lock() {
+                labels.remove(&window_label);
+            }
+        }
+    });
+    Ok(())
+}
+
+// Close every route window; their pages hold the API token
+pub fn close_route_windows(app: &AppHandle) {
+    let labels = match app.state::<RouteWindows>().0.lock() {
+        Ok(mut labels) => std::mem::take(&mut *labels),
+        Err(_) => return,
+    };
+    for label in labels {
+        if let Some(window) = app.get_webview_window(&label) {
+            let _ = window.destroy();
+        }
+    }
+}
+
+// The base URL with `route` appended, refused when `..` or the like would leave it
+fn route_url(base: &Url, route: &str) -> Result<Url, String> {
+    // "//host/..." is protocol-relative and would resolve to another host
+    if !route.starts_with('/') || route.starts_with("//") {
+        return Err(format!("Route {:?} must start with a single '/'", route));
+    }
+    let url = Url::parse(&format!("{}{}", base.as_str().trim_end_matches('/'), route))
+        .map_err(|e| format!("Route {:?} is not a valid path: {}", route, e))?;
+    if !within_base(base, &url) {
+        return Err(format!("Route {:?} leaves {}", route, base));
+    }
+    Ok(url)
+}
+
+// Same origin as the base URL and under its path
+fn within_base(base: &Url, url: &Url) -> bool {
+    let prefix = base.path().trim_end_matches('/');
+    let path = url.path();
+    url.origin() == base.origin() && (path == prefix || path.starts_with(&format!("{}/", prefix)))
+}
+
+// A window's entry in tauri.conf.json; both windows there are created on demand
+fn window_config(app: &AppHandle, label: &str) -> tauri::Result<WindowConfig> {
+    app.config()
+        .app
+        .windows
+        .iter()
+        .find(|window| window.label == label)
+        .cloned()
+        .ok_or(tauri::Error::WindowNotFound)
+}
+
+// Small "starting backend…" window shown while the main window waits on the API server.
+// splash.html draws its progress bar from api-startup-progress events.
+pub fn open_splash_window(app: &AppHandle) -> tauri::Result<()> {
+    WebviewWindowBuilder::new(app, SPLASH_WINDOW, WebviewUrl::App("splash.html".into()))
+        .title("AEGIS")
+        .inner_size(360.0, 220.0)
+        .resizable(false)
+        .decorations(false)
+        .skip_taskbar(true)
+        .always_on_top(true)
+        .theme(theme::current(app).as_theme())
+        .center()
+        .build()?;
+    Ok(())
+}
+
+pub fn close_splash_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(SPLASH_WINDOW) {
+        let _ = window.destroy();
+    }
+}
@@ -0,0 +1,199 @@
+// ==============================================================================
+// file_id: SOM-SCR-0113-v1.0.0
+// name: wsl.rs
+// description: AEGIS Desktop - Running the API server inside WSL on Windows
+// project_id: AEGIS
+// category: desktop
+// tags: [tauri, rust, desktop, process, windows, wsl]
+// created: 2026-10-14
+// modified: 2026-10-14
+// version: 1.0.0
+// ==============================================================================
+
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+
+#[cfg(target_os = "windows")]
+use crate::server::CREATE_NO_WINDOW;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+const WSL_EXE: &str = "wsl.exe";
+
+// Per-user WSL 2 settings in the Windows profile directory
+const WSL_CONFIG_FILE: &str = ".wslconfig";
+
+// Host the API server is reached on, resolved once per distro
+static RESOLVED_HOST: Mutex<Option<(Option<String>, String)>> = Mutex::new(None);
+
+// `wsl.exe`, with `-d <distro>` when one is configured
+pub fn command(distro: Option<&str>) -> Command {
+    let mut command = Command::new(WSL_EXE);
+    if let Some(distro) = distro {
+        command.args(["-d", distro]);
+    }
+    #[cfg(target_os = "windows")]
+    command.creation_flags(CREATE_NO_WINDOW);
+    command
+}
+
+// `npm run <script>` inside the distro. `dashboard_path` may be a Windows path or a path
+// inside WSL; `--cd` accepts both.
+pub fn npm_command(distro: Option<&str>, dashboard_path: &Path, script: &str) -> Command {
+    let mut command = command(distro);
+    command
+        .arg("--cd")
+        .arg(dashboard_path)
+        .args(["-e", "npm", "run", script]);
+    command
+}
+
+// WSLENV with `names` added, so those variables cross from Windows into WSL
+pub fn wslenv<'a>(names: impl IntoIterator<Item = &'a str>) -> String {
+    let mut entries: Vec<String> = std::env::var("WSLENV")
+        .ok()
+        .filter(|inherited| !inherited.is_empty())
+        .into_iter()
+        .collect();
+    entries.extend(names.into_iter().map(|name| format!("{}/u", name)));
+    entries.join(":")
+}
+
+// The distro the server will run in: the configured one if it is installed, otherwise
+// the default. Err says what is missing: WSL itself, any distro, or the configured one.
+pub fn check_distro(distro: Option<&str>) -> Result<String, String> {
+    let mut list = command(None);
+    let output = list.args(["--list", "--quiet"]).output().map_err(|e| {
+        format!(
+            "WSL is not available ({}). Install it with `wsl --install` or set \
+             api.backend_transport = \"native\"",
+            e
+        )
+    })?;
+    if !output.status.success() {
+        return Err(format!(
+            "`wsl --list` exited with {}; is WSL installed?",
+            output.status
+        ));
+    }
+
+    let installed = parse_list(&output.stdout);
+    match distro {
+        Some(name) => installed
+            .iter()
+            .find(|installed| installed.eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "WSL distro {:?} was not found; installed: {}",
+                    name,
+                    if installed.is_empty() {
+                        "none".to_string()
+                    } else {
+                        installed.join(", ")
+                    }
+                )
+            }),
+        // The default distro is listed first
+        None => installed
+            .into_iter()
+            .next()
+            .ok_or_else(|| "WSL has no distro installed; add one with `wsl --install`".into()),
+    }
+}
+
+// localhost while WSL forwards it (the default, and always with mirrored networking);
+// otherwise the distro's own address. Runs wsl.exe the first time when forwarding is off.
+pub fn host(distro: Option<&str>) -> String {
+    let mut cached = RESOLVED_HOST
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some((resolved_for, host)) = cached.as_ref() {
+        if resolved_for.as_deref() == distro {
+            return host.clone();
+        }
+    }
+
+    let host = if localhost_forwarded() {
+        "localhost".to_string()
+    } else {
+        match distro_address(distro) {
+            Ok(address) => {
+                tracing::info!(
+                    address,
+                    "wsl localhost forwarding is off; using the distro address"
+                );
+                address
+            }
+            // Not cached, so the next request tries again
+            Err(e) => {
+                tracing::warn!(error = %e, "could not resolve the wsl address; using localhost");
+                return "localhost".to_string();
+            }
+        }
+    };
+    *cached = Some((distro.map(String::from), host.clone()));
+    host
+}
+
+fn distro_address(distro: Option<&str>) -> Result<String, String> {
+    let mut hostname = command(distro);
+    let output = hostname
+        .args(["-e", "hostname", "-I"])
+        .output()
+        .map_err(|e| e.to_string())?;
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .next()
+        .map(String::from)
+        .ok_or_else(|| "`hostname -I` printed no address".into())
+}
+
+// Reads localhostForwarding and networkingMode from the [wsl2] section of .wslconfig
+fn localhost_forwarded() -> bool {
+    let Some(profile) = std::env::var_os("USERPROFILE") else {
+        return true;
+    };
+    let Ok(text) = std::fs::read_to_string(Path::new(&profile).join(WSL_CONFIG_FILE)) else {
+        return true;
+    };
+
+    let mut in_wsl2 = false;
+    let mut forwarding = true;
+    let mut mirrored = false;
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_wsl2 = line.eq_ignore_ascii_case("[wsl2]");
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=').filter(|_| in_wsl2) else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if key.eq_ignore_ascii_case("localhostForwarding") {
+            forwarding = !value.eq_ignore_ascii_case("false");
+        } else if key.eq_ignore_ascii_case("networkingMode") {
+            mirrored = value.eq_ignore_ascii_case("mirrored");
+        }
+    }
+    forwarding || mirrored
+}
+
+// wsl.exe writes its own output as UTF-16LE; UTF-8 never contains a NUL byte
+fn parse_list(stdout: &[u8]) -> Vec<String> {
+    let text = if stdout.contains(&0) {
+        let units: Vec<u16> = stdout
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        String::from_utf8_lossy(stdout).into_owned()
+    };
+    text.lines()
+        .map(|line| line.trim().trim_start_matches('\u{feff}'))
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}